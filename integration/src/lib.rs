@@ -0,0 +1,8 @@
+//! Behavior tests for `target-notifier`. The derive needs nightly, so they
+//! live outside the published crate: run `cargo test --features <backend>`
+//! from this directory.
+
+#[cfg(not(feature = "std"))]
+pub fn drain<T>(receiver: &mut target_notifier::Receiver<'_, T>) -> Vec<T> {
+    core::iter::from_fn(|| receiver.try_recv().ok()).collect()
+}
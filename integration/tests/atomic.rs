@@ -0,0 +1,70 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service(overflow = DropOldest)]
+    oldest: Service<u32, 2>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 1>,
+}
+
+fn notifier() -> Notif {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    notif
+}
+
+#[test]
+fn atomic_send_rolls_back() {
+    let notif = notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let mut small = notif.small().receiver::<u32>();
+    let sender = notif.ui().sender();
+    sender.send_to([Targets::SMALL], 0).unwrap();
+
+    assert!(sender
+        .send_to_atomic([Targets::LOGIC, Targets::SMALL], 1)
+        .is_err());
+    assert!(drain(&mut logic).is_empty());
+    assert_eq!(drain(&mut small), [0]);
+
+    sender
+        .send_to_atomic([Targets::LOGIC, Targets::SMALL], 2)
+        .unwrap();
+    assert_eq!(drain(&mut logic), [2]);
+    assert_eq!(drain(&mut small), [2]);
+}
+
+#[test]
+fn failed_atomic_send_keeps_queued_events() {
+    let notif = notifier();
+    let mut oldest = notif.oldest().receiver::<u32>();
+    let mut small = notif.small().receiver::<u32>();
+    let mut logic = notif.logic().receiver::<u32>();
+    let sender = notif.ui().sender();
+    sender.send_to([Targets::OLDEST], 1).unwrap();
+    sender.send_to([Targets::OLDEST], 2).unwrap();
+    sender.send_to([Targets::SMALL], 0).unwrap();
+
+    assert!(sender
+        .send_to_atomic([Targets::OLDEST, Targets::SMALL], 3)
+        .is_err());
+    assert_eq!(drain(&mut oldest), [1, 2]);
+    assert_eq!(drain(&mut small), [0]);
+
+    sender.send_to([Targets::OLDEST], 1).unwrap();
+    sender.send_to([Targets::OLDEST], 2).unwrap();
+    sender
+        .send_to_atomic([Targets::OLDEST, Targets::LOGIC], 4)
+        .unwrap();
+    assert_eq!(drain(&mut oldest), [2, 4]);
+    assert_eq!(drain(&mut logic), [4]);
+}
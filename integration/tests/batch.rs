@@ -0,0 +1,37 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 2>,
+}
+
+#[test]
+fn batch_counts_accepted_events_per_target() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let mut small = notif.small().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    let report = sender.send_batch([1, 2, 3]).unwrap();
+    assert_eq!(report.events(), 3);
+    assert_eq!(report.accepted(Targets::LOGIC), 3);
+    assert_eq!(report.accepted(Targets::SMALL), 2);
+    assert!(!report.is_delivered());
+    assert_eq!(drain(&mut logic), [1, 2, 3]);
+    assert_eq!(drain(&mut small), [1, 2]);
+
+    let report = sender.send_batch_to([Targets::SMALL], [4, 5]).unwrap();
+    assert!(report.is_delivered());
+    assert_eq!(report.accepted(Targets::LOGIC), 0);
+    assert_eq!(drain(&mut small), [4, 5]);
+}
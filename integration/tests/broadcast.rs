@@ -0,0 +1,35 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{BroadcastService, Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    feed: BroadcastService<u32, 4, 2>,
+}
+
+#[test]
+fn broadcast_fan_out() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut first = notif.feed().receiver::<u32>();
+    let mut second = first.try_clone().unwrap();
+    assert!(first.try_clone().is_none());
+    let sender = notif.ui().sender();
+
+    sender.send_to([Targets::FEED], 1).unwrap();
+    sender.send_to([Targets::FEED], 2).unwrap();
+    assert_eq!(drain(&mut first), [1, 2]);
+    assert_eq!(drain(&mut second), [1, 2]);
+
+    let inactive = second.deactivate();
+    assert!(inactive.try_activate().is_some());
+    let third = inactive.try_activate().unwrap();
+    assert!(inactive.try_activate().is_none());
+    drop(third);
+    assert!(inactive.try_activate().is_some());
+}
@@ -0,0 +1,35 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Coalesce, CoalesceService, Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Reading(u32, u32);
+impl Coalesce for Reading {
+    type Key = u32;
+    fn key(&self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<Reading, 4>,
+    #[service]
+    readings: CoalesceService<Reading, 4>,
+}
+
+#[test]
+fn coalescing() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut readings = notif.readings().receiver::<Reading>();
+    let sender = notif.ui().sender();
+
+    sender.send(Reading(1, 10)).unwrap();
+    sender.send(Reading(2, 20)).unwrap();
+    sender.send(Reading(1, 11)).unwrap();
+    assert_eq!(drain(&mut readings), [Reading(1, 11), Reading(2, 20)]);
+}
@@ -0,0 +1,33 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Envelope, Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service(envelope)]
+    audit: Service<Envelope<u32>, 4>,
+}
+
+#[test]
+fn envelopes_carry_source_and_sequence() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut audit = notif.audit().receiver::<Envelope<u32>>();
+    notif.ui().sender().send_to([Targets::AUDIT], 1).unwrap();
+    notif.logic().sender().send_to([Targets::AUDIT], 2).unwrap();
+
+    let envelopes = drain(&mut audit);
+    let stamps: Vec<_> = envelopes
+        .iter()
+        .map(|envelope| (Targets::from(envelope.from), envelope.payload))
+        .collect();
+    assert_eq!(stamps, [(Targets::UI, 1), (Targets::LOGIC, 2)]);
+    assert!(envelopes[0].seq < envelopes[1].seq);
+    assert_eq!(envelopes[1].clone().into_payload(), 2);
+}
@@ -0,0 +1,66 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use core::any::Any;
+use target_notifier::{Error, Interceptor, Interceptors, Notifier, Outgoing, Service, Verdict};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    storage: Service<u32, 4>,
+    #[interceptors]
+    chain: Interceptors<2>,
+}
+
+struct Rules;
+impl Interceptor for Rules {
+    fn intercept(&self, outgoing: &Outgoing<'_>, event: &mut dyn Any) -> Verdict {
+        match event.downcast_mut::<u32>() {
+            Some(0) if Targets::from(*outgoing.to) == Targets::STORAGE => Verdict::Veto,
+            Some(7) => Verdict::Duplicate(1),
+            _ => Verdict::Pass,
+        }
+    }
+}
+
+#[test]
+fn interceptors_veto_and_duplicate() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    assert!(notif.chain.register(&Rules));
+    let mut logic = notif.logic().receiver::<u32>();
+    let mut storage = notif.storage().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    match sender.send(0) {
+        Err(Error::Send(report)) => {
+            assert_eq!(
+                report.vetoed().collect::<Vec<_>>(),
+                [&Targets::STORAGE.into()]
+            )
+        }
+        res => panic!("unexpected {res:?}"),
+    }
+    assert_eq!(drain(&mut logic), [0]);
+    assert!(drain(&mut storage).is_empty());
+
+    sender.send_to([Targets::LOGIC], 7).unwrap();
+    assert_eq!(drain(&mut logic), [7, 7]);
+
+    assert!(sender
+        .send_to_atomic([Targets::LOGIC, Targets::STORAGE], 0)
+        .is_err());
+    assert!(drain(&mut logic).is_empty());
+
+    let permit = sender.reserve::<_, u32>(Targets::STORAGE).unwrap();
+    assert!(permit.send(0).is_vetoed());
+
+    notif.chain.clear();
+    sender.send_to([Targets::STORAGE], 0).unwrap();
+    assert_eq!(drain(&mut storage), [0]);
+}
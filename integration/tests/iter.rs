@@ -0,0 +1,35 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    storage: Service<u32, 4>,
+}
+
+#[test]
+fn runtime_target_lists() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let mut storage = notif.storage().receiver::<u32>();
+    let sender = notif.ui().sender();
+    let targets: Vec<target_notifier::ID> = vec![Targets::LOGIC.into(), Targets::STORAGE.into()];
+
+    sender.send_to_iter(targets.iter().copied(), 1).unwrap();
+    assert_eq!(drain(&mut logic), [1]);
+    assert_eq!(drain(&mut storage), [1]);
+
+    sender
+        .send_filtered_iter(targets[..1].iter().copied(), 2)
+        .unwrap();
+    assert!(drain(&mut logic).is_empty());
+    assert_eq!(drain(&mut storage), [2]);
+}
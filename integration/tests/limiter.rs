@@ -0,0 +1,88 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use futures_util::FutureExt;
+use target_notifier::{Error, Limiter, ManualClock, Notifier, Scheduler, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 1>,
+}
+
+fn notifier() -> Notif {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    notif
+}
+
+#[test]
+fn rate_limit_refills_after_period() {
+    let notif = notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let clock = ManualClock::new();
+    let limiter = Limiter::<_, 4>::new(&clock);
+    assert!(limiter.set_rate(Targets::LOGIC, 1, 10));
+    let sender = notif.ui().sender().with_limiter(&limiter);
+
+    sender.send_to([Targets::LOGIC], 1).unwrap();
+    match sender.send_to([Targets::LOGIC], 2) {
+        Err(Error::Send(report)) => assert_eq!(report.rate_limited().count(), 1),
+        res => panic!("unexpected {res:?}"),
+    }
+    assert_eq!(limiter.dropped(Targets::LOGIC), 1);
+
+    clock.advance(10);
+    sender.send_to([Targets::LOGIC], 3).unwrap();
+    assert_eq!(drain(&mut logic), [1, 3]);
+}
+
+#[test]
+fn failed_atomic_send_spends_no_token() {
+    let notif = notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let _small = notif.small().receiver::<u32>();
+    let clock = ManualClock::new();
+    let limiter = Limiter::<_, 4>::new(&clock);
+    limiter.set_rate(Targets::LOGIC, 1, 10);
+    let sender = notif.ui().sender().with_limiter(&limiter);
+    sender.send_to([Targets::SMALL], 0).unwrap();
+
+    assert!(sender
+        .send_to_atomic([Targets::LOGIC, Targets::SMALL], 1)
+        .is_err());
+    sender.send_to_atomic([Targets::LOGIC], 2).unwrap();
+    assert!(sender.send_to_atomic([Targets::LOGIC], 3).is_err());
+    assert_eq!(drain(&mut logic), [2]);
+}
+
+#[test]
+fn debounce_keeps_the_last_event() {
+    let notif = notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let clock = ManualClock::new();
+    let limiter = Limiter::<_, 4>::new(&clock);
+    let scheduler = Scheduler::<_, u32>::new(&clock);
+    assert!(limiter.set_debounce(Targets::LOGIC, 5));
+    let sender = notif.ui().sender();
+
+    for event in 1..=3 {
+        sender
+            .send_debounced(&limiter, &scheduler, Targets::LOGIC, event)
+            .unwrap();
+    }
+    assert_eq!(scheduler.pending(), 1);
+    assert_eq!(limiter.dropped(Targets::LOGIC), 2);
+
+    let mut run = Box::pin(scheduler.run(&notif));
+    assert!(run.as_mut().now_or_never().is_none());
+    assert!(drain(&mut logic).is_empty());
+    clock.advance(5);
+    assert!(run.as_mut().now_or_never().is_none());
+    assert_eq!(drain(&mut logic), [3]);
+}
@@ -0,0 +1,33 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+}
+
+#[test]
+fn loopback() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut ui = notif.ui().receiver::<u32>();
+    let mut logic = notif.logic().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    sender.send(1).unwrap();
+    assert!(drain(&mut ui).is_empty());
+    assert_eq!(drain(&mut logic), [1]);
+
+    sender.with_loopback(true).send(2).unwrap();
+    assert_eq!(drain(&mut ui), [2]);
+    assert_eq!(drain(&mut logic), [2]);
+
+    sender.send_to([Targets::UI], 3).unwrap();
+    assert_eq!(drain(&mut ui), [3]);
+}
@@ -0,0 +1,40 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{BroadcastService, DynamicServiceMetrics, Notifier, Service};
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 2>,
+    #[service]
+    feed: BroadcastService<u32, 4, 2>,
+}
+
+#[test]
+fn metrics_track_deliveries_and_drops() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    for event in 1..=3 {
+        let _ = sender.send_to([Targets::LOGIC], event);
+    }
+    assert_eq!(logic.try_recv().unwrap(), 1);
+    let metrics = notif.logic.get_metrics();
+    assert_eq!((metrics.sent, metrics.dropped_full), (2, 1));
+    assert_eq!(
+        (metrics.received, metrics.depth, metrics.high_water),
+        (1, 1, 2)
+    );
+
+    drop(logic);
+    sender.send(4).unwrap_err();
+    assert_eq!(notif.logic.get_metrics().dropped_no_receiver, 1);
+
+    sender.send_to([Targets::FEED], 5).unwrap_err();
+    assert_eq!(notif.feed.get_metrics().dropped_no_receiver, 1);
+}
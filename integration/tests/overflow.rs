@@ -0,0 +1,38 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{DynamicServiceMetrics, Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 1>,
+    #[service(overflow = DropOldest)]
+    oldest: Service<u32, 2>,
+    #[service(overflow = KeepLatest)]
+    latest: Service<u32, 2>,
+}
+
+#[test]
+fn overflow_policies() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut small = notif.small().receiver::<u32>();
+    let mut oldest = notif.oldest().receiver::<u32>();
+    let mut latest = notif.latest().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    for event in 1..=3 {
+        let res = sender.send_to([Targets::SMALL], event);
+        assert_eq!(res.is_ok(), event == 1);
+        sender.send_to([Targets::OLDEST], event).unwrap();
+        sender.send_to([Targets::LATEST], event).unwrap();
+    }
+    assert_eq!(drain(&mut small), [1]);
+    assert_eq!(drain(&mut oldest), [2, 3]);
+    assert_eq!(drain(&mut latest), [3]);
+    assert_eq!(notif.oldest.get_metrics().dropped_full, 1);
+}
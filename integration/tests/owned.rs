@@ -0,0 +1,33 @@
+#![cfg(any(feature = "async-channel", feature = "async-std"))]
+#![feature(impl_trait_in_assoc_type)]
+use std::sync::Arc;
+use target_notifier::{Notifier, OwnedReceiver, OwnedSender, Service};
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+}
+
+#[test]
+fn spawned_handles_deliver() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let notif = Arc::new(notif);
+    let sender = OwnedSender::new(notif.clone(), Targets::UI);
+    let mut receiver = OwnedReceiver::<_, u32>::new(notif, Targets::LOGIC).unwrap();
+
+    let received = async_std::task::spawn(async move {
+        let mut events = [receiver.recv().await, receiver.recv().await];
+        events.sort();
+        events
+    });
+    for event in [1, 2] {
+        let sender = sender.clone();
+        async_std::task::spawn(async move { sender.sender().send(event).unwrap() });
+    }
+    assert_eq!(async_std::task::block_on(received), [1, 2]);
+}
@@ -0,0 +1,68 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use core::sync::atomic::{AtomicUsize, Ordering};
+use target_notifier::{Notifier, Service};
+use target_notifier_integration::drain;
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq)]
+struct Payload(u32);
+impl Clone for Payload {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::Relaxed);
+        Self(self.0)
+    }
+}
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<Payload, 4>,
+    #[service]
+    logic: Service<Payload, 4>,
+    #[service]
+    storage: Service<Payload, 4>,
+}
+
+#[test]
+fn last_recipient_gets_the_original() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<Payload>();
+    let mut storage = notif.storage().receiver::<Payload>();
+
+    notif.ui().sender().send(Payload(1)).unwrap();
+    assert_eq!(CLONES.load(Ordering::Relaxed), 1);
+    assert_eq!(drain(&mut logic), [Payload(1)]);
+    assert_eq!(drain(&mut storage), [Payload(1)]);
+}
+
+#[cfg(any(feature = "async-channel", feature = "async-std"))]
+#[test]
+fn shared_payloads_are_not_copied() {
+    use target_notifier::Shared;
+
+    #[derive(Notifier, Default)]
+    #[notifier(targets: SharedTargets)]
+    struct SharedNotif {
+        #[service]
+        ui: Service<Shared<Vec<u8>>, 4>,
+        #[service]
+        logic: Service<Shared<Vec<u8>>, 4>,
+        #[service]
+        storage: Service<Shared<Vec<u8>>, 4>,
+    }
+
+    let mut notif = SharedNotif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<Shared<Vec<u8>>>();
+    let mut storage = notif.storage().receiver::<Shared<Vec<u8>>>();
+
+    notif.ui().sender().send(Shared::new(vec![1, 2])).unwrap();
+    let (first, second) = (logic.try_recv().unwrap(), storage.try_recv().unwrap());
+    assert!(Shared::ptr_eq(&first, &second));
+    drop(first);
+    assert_eq!(Shared::try_unwrap(second).unwrap(), [1, 2]);
+}
@@ -0,0 +1,27 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Notifier, PriorityService, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    lanes: PriorityService<u32, 4, 3>,
+}
+
+#[test]
+fn priority_order() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut lanes = notif.lanes().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    sender.send_with_priority(0, 1).unwrap();
+    sender.send_with_priority(2, 2).unwrap();
+    sender.send_with_priority(1, 3).unwrap();
+    sender.send_with_priority(5, 4).unwrap();
+    assert_eq!(drain(&mut lanes), [2, 4, 3, 1]);
+}
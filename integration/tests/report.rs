@@ -0,0 +1,43 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{BroadcastService, Error, Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 1>,
+    #[service]
+    feed: BroadcastService<u32, 4, 2>,
+}
+
+#[test]
+fn report_lists_every_failed_target() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let _small = notif.small().receiver::<u32>();
+    let sender = notif.ui().sender();
+    sender.send_to([Targets::SMALL], 0).unwrap();
+
+    let report = match sender.send_to([Targets::LOGIC, Targets::SMALL, Targets::FEED], 1) {
+        Err(Error::Send(report)) => report,
+        res => panic!("unexpected {res:?}"),
+    };
+    assert_eq!(
+        report.delivered().collect::<Vec<_>>(),
+        [&Targets::LOGIC.into()]
+    );
+    assert_eq!(report.full().collect::<Vec<_>>(), [&Targets::SMALL.into()]);
+    assert_eq!(
+        report.disconnected().collect::<Vec<_>>(),
+        [&Targets::FEED.into()]
+    );
+    assert_eq!(report.into_undelivered().count(), 2);
+    assert_eq!(drain(&mut logic), [1]);
+}
@@ -0,0 +1,43 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use futures_util::FutureExt;
+use target_notifier::{Error, Notifier, Request, RequestService, Service};
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    rpc: RequestService<Request<u32, u32>, 2>,
+}
+
+#[test]
+fn request_response() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut rpc = notif.rpc().receiver::<Request<u32, u32>>();
+    let sender = notif.ui().sender();
+
+    let mut request = Box::pin(sender.request::<_, u32, u32>(Targets::RPC, 20));
+    assert!(request.as_mut().now_or_never().is_none());
+    let (payload, responder) = rpc.try_recv_request().unwrap();
+    responder.respond(payload + 1).unwrap();
+    assert_eq!(futures_executor::block_on(request).unwrap(), 21);
+
+    let mut request = Box::pin(sender.request::<_, u32, u32>(Targets::RPC, 30));
+    assert!(request.as_mut().now_or_never().is_none());
+    drop(rpc.try_recv_request().unwrap());
+    assert!(matches!(
+        futures_executor::block_on(request),
+        Err(Error::NoResponse)
+    ));
+
+    let mut request = Box::pin(sender.request::<_, u32, u32>(Targets::RPC, 40));
+    assert!(request.as_mut().now_or_never().is_none());
+    drop(rpc);
+    assert!(matches!(
+        futures_executor::block_on(request),
+        Err(Error::NoResponse)
+    ));
+}
@@ -0,0 +1,44 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Error, Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+#[notifier(allow(guest = [ui]))]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    guest: Service<u32, 4>,
+}
+
+#[test]
+fn allow_list() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut ui = notif.ui().receiver::<u32>();
+    let mut logic = notif.logic().receiver::<u32>();
+    let sender = notif.guest().sender();
+
+    sender.send(1).unwrap();
+    assert_eq!(drain(&mut ui), [1]);
+    assert!(drain(&mut logic).is_empty());
+
+    assert!(matches!(
+        sender.send_to([Targets::LOGIC], 2),
+        Err(Error::Forbidden(_))
+    ));
+    assert!(matches!(
+        sender.route_to::<_, u32, 1>([Targets::LOGIC]),
+        Err(Error::Forbidden(_))
+    ));
+    sender.send_to([Targets::UI], 3).unwrap();
+    assert_eq!(drain(&mut ui), [3]);
+
+    let routed = notif.guest().route::<{ Targets::UI.id() }, u32>().unwrap();
+    routed.send(4).unwrap();
+    assert_eq!(drain(&mut ui), [4]);
+}
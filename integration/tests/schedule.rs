@@ -0,0 +1,59 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use futures_util::FutureExt;
+use target_notifier::{ManualClock, Notifier, Scheduler, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 1>,
+    #[service]
+    logic: Service<u32, 4>,
+}
+
+#[test]
+fn full_target_does_not_stall_the_scheduler() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut small = notif.small().receiver::<u32>();
+    let mut logic = notif.logic().receiver::<u32>();
+    let clock = ManualClock::new();
+    let scheduler = Scheduler::<_, u32>::new(&clock);
+    let sender = notif.ui().sender();
+    sender.send_to([Targets::SMALL], 0).unwrap();
+
+    sender.send_at(&scheduler, 1, Targets::SMALL, 1).unwrap();
+    sender.send_after(&scheduler, 2, Targets::LOGIC, 2).unwrap();
+    assert_eq!(scheduler.pending(), 2);
+
+    let mut run = Box::pin(scheduler.run(&notif));
+    assert!(run.as_mut().now_or_never().is_none());
+    assert!(drain(&mut logic).is_empty());
+
+    clock.advance(2);
+    assert!(run.as_mut().now_or_never().is_none());
+    assert_eq!(scheduler.pending(), 0);
+    assert_eq!(drain(&mut small), [0]);
+    assert_eq!(drain(&mut logic), [2]);
+}
+
+#[test]
+fn cancel_drops_pending_sends() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let clock = ManualClock::new();
+    let scheduler = Scheduler::<_, u32>::new(&clock);
+    let sender = notif.ui().sender();
+
+    sender.send_after(&scheduler, 1, Targets::LOGIC, 1).unwrap();
+    assert_eq!(scheduler.cancel(Targets::LOGIC), 1);
+    let mut run = Box::pin(scheduler.run(&notif));
+    clock.advance(1);
+    assert!(run.as_mut().now_or_never().is_none());
+    assert!(drain(&mut logic).is_empty());
+}
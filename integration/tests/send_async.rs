@@ -0,0 +1,34 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use futures_util::FutureExt;
+use target_notifier::{Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 1>,
+}
+
+#[test]
+fn send_async_waits_for_capacity() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut small = notif.small().receiver::<u32>();
+    let sender = notif.ui().sender();
+    sender.send_to([Targets::SMALL], 1).unwrap();
+
+    let mut send = Box::pin(sender.send_to_async([Targets::SMALL], 2));
+    assert!(send.as_mut().now_or_never().is_none());
+    assert_eq!(drain(&mut small), [1]);
+    futures_executor::block_on(send).unwrap();
+
+    let mut send = Box::pin(sender.send_async(3));
+    assert!(send.as_mut().now_or_never().is_none());
+    assert_eq!(futures_executor::block_on(small.recv()), 2);
+    futures_executor::block_on(send).unwrap();
+    assert_eq!(drain(&mut small), [3]);
+}
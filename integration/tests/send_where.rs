@@ -0,0 +1,36 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    storage: Service<u32, 4>,
+}
+
+#[test]
+fn predicate_routing() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut ui = notif.ui().receiver::<u32>();
+    let mut logic = notif.logic().receiver::<u32>();
+    let mut storage = notif.storage().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    sender
+        .send_where(|id, _| Targets::from(*id) == Targets::STORAGE, 1)
+        .unwrap();
+    assert!(drain(&mut logic).is_empty());
+    assert_eq!(drain(&mut storage), [1]);
+
+    futures_executor::block_on(sender.send_where_async(|_, state| state.is_active(), 2)).unwrap();
+    assert_eq!(drain(&mut ui), [2]);
+    assert_eq!(drain(&mut logic), [2]);
+    assert_eq!(drain(&mut storage), [2]);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+#![feature(impl_trait_in_assoc_type)]
+use std::thread;
+use target_notifier::{Error, Notifier, Service};
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    small: Service<u32, 2>,
+}
+
+#[test]
+fn bounded_channel_reports_full() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut small = notif.small().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    sender.send_to([Targets::SMALL], 1).unwrap();
+    sender.send_to([Targets::SMALL], 2).unwrap();
+    match sender.send_to([Targets::SMALL], 3) {
+        Err(Error::Send(report)) => {
+            assert_eq!(report.full().collect::<Vec<_>>(), [&Targets::SMALL.into()])
+        }
+        res => panic!("unexpected {res:?}"),
+    }
+    assert_eq!(small.try_recv().unwrap(), 1);
+    sender.send_to([Targets::SMALL], 3).unwrap();
+    assert_eq!(small.try_recv().unwrap(), 2);
+    assert_eq!(small.try_recv().unwrap(), 3);
+}
+
+#[test]
+fn cloned_receivers_share_the_queue() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut first = notif.small().receiver::<u32>();
+    let mut second = first.clone();
+    let sender = notif.ui().sender();
+
+    sender.send_to([Targets::SMALL], 1).unwrap();
+    sender.send_to([Targets::SMALL], 2).unwrap();
+    assert_eq!(first.try_recv().unwrap(), 1);
+    assert_eq!(second.try_recv().unwrap(), 2);
+    assert!(first.try_recv().is_err());
+
+    thread::scope(|scope| {
+        let first = scope.spawn(|| notif.small().receiver::<u32>().recv());
+        let second = scope.spawn(|| notif.small().receiver::<u32>().recv());
+        sender.send_to([Targets::SMALL], 3).unwrap();
+        sender.send_to([Targets::SMALL], 4).unwrap();
+        let mut events = [first.join().unwrap(), second.join().unwrap()];
+        events.sort();
+        assert_eq!(events, [3, 4]);
+    });
+}
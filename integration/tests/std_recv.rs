@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+#![feature(impl_trait_in_assoc_type)]
+use std::{thread, time::Duration};
+use target_notifier::{Notifier, RecvTimeoutError, Service};
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+}
+
+#[test]
+fn blocking_and_non_blocking_receives() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    assert!(logic.try_recv().is_err());
+    sender.send(1).unwrap();
+    assert_eq!(logic.try_recv().unwrap(), 1);
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            thread::sleep(Duration::from_millis(20));
+            notif.ui().sender().send(2).unwrap();
+        });
+        assert_eq!(logic.recv(), 2);
+    });
+}
+
+#[test]
+fn recv_timeout_waits_up_to_the_deadline() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+
+    assert_eq!(
+        logic.recv_timeout(Duration::from_millis(10)),
+        Err(RecvTimeoutError::Timeout)
+    );
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            thread::sleep(Duration::from_millis(20));
+            notif.ui().sender().send(3).unwrap();
+        });
+        assert_eq!(logic.recv_timeout(Duration::from_secs(5)), Ok(3));
+    });
+}
@@ -0,0 +1,40 @@
+#![cfg(any(feature = "async-channel", feature = "async-std"))]
+#![feature(impl_trait_in_assoc_type)]
+use futures_util::StreamExt;
+use target_notifier::{Coalesce, CoalesceService, Notifier, Service};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Reading(u32, u32);
+impl Coalesce for Reading {
+    type Key = u32;
+    fn key(&self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<Reading, 4>,
+    #[service]
+    logic: Service<Reading, 4>,
+    #[service]
+    readings: CoalesceService<Reading, 4>,
+}
+
+#[test]
+fn receivers_are_streams() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let logic = notif.logic().receiver::<Reading>();
+    let readings = notif.readings().receiver::<Reading>();
+    let sender = notif.ui().sender();
+    sender.send(Reading(1, 10)).unwrap();
+    sender.send(Reading(2, 20)).unwrap();
+
+    let events = futures_executor::block_on(logic.take(2).collect::<Vec<_>>());
+    assert_eq!(events, [Reading(1, 10), Reading(2, 20)]);
+    let events = futures_executor::block_on(readings.take(2).collect::<Vec<_>>());
+    assert_eq!(events, [Reading(1, 10), Reading(2, 20)]);
+}
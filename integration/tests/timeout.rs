@@ -0,0 +1,39 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use futures_util::FutureExt;
+use target_notifier::{ManualClock, Notifier, RecvTimeoutError, Service};
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+}
+
+#[test]
+fn recv_timeout_expires_without_events() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let clock = ManualClock::new();
+
+    let mut recv = Box::pin(logic.recv_timeout(&clock, 5));
+    assert!(recv.as_mut().now_or_never().is_none());
+    clock.advance(5);
+    assert_eq!(
+        futures_executor::block_on(recv),
+        Err(RecvTimeoutError::Timeout)
+    );
+
+    notif.ui().sender().send(1).unwrap();
+    assert_eq!(
+        futures_executor::block_on(logic.recv_timeout(&clock, 5)),
+        Ok(1)
+    );
+    assert_eq!(
+        futures_executor::block_on(logic.recv_deadline(&clock, 5)),
+        Err(RecvTimeoutError::Timeout)
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(not(feature = "std"))]
+#![feature(impl_trait_in_assoc_type)]
+use target_notifier::{Error, Notifier, Service};
+use target_notifier_integration::drain;
+
+#[derive(Notifier, Default)]
+#[notifier(targets: Targets)]
+struct Notif {
+    #[service]
+    ui: Service<u32, 4>,
+    #[service]
+    logic: Service<u32, 4>,
+    #[service]
+    storage: Service<u32, 4>,
+}
+
+#[test]
+fn typed_senders_resolve_targets_once() {
+    let mut notif = Notif::default();
+    notif.init_notifier();
+    let mut logic = notif.logic().receiver::<u32>();
+    let mut storage = notif.storage().receiver::<u32>();
+    let sender = notif.ui().sender();
+
+    let typed = sender.for_type::<u32>().unwrap();
+    assert_eq!(typed.targets().count(), 2);
+    typed.send(1).unwrap();
+    assert_eq!(drain(&mut logic), [1]);
+    assert_eq!(drain(&mut storage), [1]);
+
+    let routed = sender.route_to::<_, u32, 1>([Targets::STORAGE]).unwrap();
+    routed.send(2).unwrap();
+    assert!(drain(&mut logic).is_empty());
+    assert_eq!(drain(&mut storage), [2]);
+
+    assert!(matches!(
+        sender.for_type_with_capacity::<u32, 1>(),
+        Err(Error::TooManyTargets(2))
+    ));
+    let routed = sender
+        .route_to_with_capacity::<_, u32, 1, 1>([Targets::LOGIC])
+        .unwrap();
+    futures_executor::block_on(routed.send_async(3)).unwrap();
+    assert_eq!(drain(&mut logic), [3]);
+}
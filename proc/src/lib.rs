@@ -5,7 +5,8 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Literal, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{
-    Attribute, Expr, ExprParen, ExprType, ItemStruct, Type, TypeArray, TypeTuple, Visibility,
+    punctuated::Punctuated, Attribute, Expr, ExprAssign, ExprParen, ExprType, ItemStruct, Token,
+    Type, TypeArray, TypeTuple, Visibility,
 };
 
 enum FieldTypes<'a> {
@@ -14,12 +15,19 @@ enum FieldTypes<'a> {
     Array(Box<FieldTypes<'a>>, &'a Expr),
 }
 
+#[derive(Default)]
+struct ServiceAttrs {
+    overflow: Option<Ident>,
+    envelope: bool,
+}
+
 struct Services<'a> {
     id: usize,
     ident: &'a Ident,
     attr: TokenStream2,
     upper: Ident,
     ty: FieldTypes<'a>,
+    service: ServiceAttrs,
 }
 
 #[derive(Debug)]
@@ -58,6 +66,60 @@ fn parse_attrs(attrs: &[Attribute]) -> HashMap<String, Ident> {
         .collect()
 }
 
+fn parse_service_attrs(attr: &Attribute) -> Result<ServiceAttrs, syn::Error> {
+    let mut ret = ServiceAttrs::default();
+    if attr.tokens.is_empty() {
+        return Ok(ret);
+    }
+    for expr in attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)? {
+        match &expr {
+            Expr::Assign(ExprAssign { left, right, .. }) => match (left.as_ref(), right.as_ref()) {
+                (Expr::Path(key), Expr::Path(value)) if key.path.is_ident("overflow") => {
+                    ret.overflow = Some(value.path.get_ident().cloned().ok_or_else(|| {
+                        syn::Error::new_spanned(value, "Expected an overflow policy")
+                    })?)
+                }
+                _ => return Err(syn::Error::new_spanned(expr, "Unknown service attribute")),
+            },
+            Expr::Path(key) if key.path.is_ident("envelope") => ret.envelope = true,
+            _ => return Err(syn::Error::new_spanned(expr, "Unknown service attribute")),
+        }
+    }
+    Ok(ret)
+}
+
+fn envelope_payload(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .filter(|segm| segm.ident == "Envelope")
+            .and_then(|segm| match &segm.arguments {
+                syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                    match args.args.first() {
+                        Some(syn::GenericArgument::Type(ty)) => Some(ty),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }),
+        _ => None,
+    }
+}
+
+fn check_envelope(ty: &FieldTypes) -> Result<(), syn::Error> {
+    match ty {
+        FieldTypes::Once(ty, _) => envelope_payload(ty)
+            .map(|_| ())
+            .ok_or_else(|| syn::Error::new_spanned(ty, "Envelope services must carry Envelope<T>")),
+        FieldTypes::Tuple(vec) => vec
+            .iter()
+            .try_for_each(|(ty, expr)| check_envelope(&FieldTypes::Once(ty, expr))),
+        FieldTypes::Array(ty, _) => check_envelope(ty),
+    }
+}
+
 fn parse_field(ty: &Type) -> Result<FieldTypes, syn::Error> {
     match ty {
         Type::Path(ty) => ty
@@ -66,12 +128,12 @@ fn parse_field(ty: &Type) -> Result<FieldTypes, syn::Error> {
             .last()
             .ok_or((quote!(#ty), "Wrong type path"))
             .and_then(|segm| match &segm.arguments {
-                syn::PathArguments::AngleBracketed(args) if args.args.len() == 2 => {
-                    Ok((args.args.first().unwrap(), args.args.last().unwrap()))
+                syn::PathArguments::AngleBracketed(args) if args.args.len() >= 2 => {
+                    Ok((&args.args[0], &args.args[1]))
                 }
                 _ => Err((
                     quote!(#segm),
-                    "The type must contain two template arguments",
+                    "The type must contain at least two template arguments",
                 )),
             })
             .and_then(|(ty, num)| match ty {
@@ -118,7 +180,7 @@ fn parse(input: &ItemStruct) -> Result<Vec<Services>, TokenStream2> {
     for (id, ident, attr, res) in input
         .fields
         .iter()
-        .filter(|field| {
+        .filter_map(|field| {
             field
                 .attrs
                 .iter()
@@ -127,18 +189,29 @@ fn parse(input: &ItemStruct) -> Result<Vec<Services>, TokenStream2> {
                         .get_ident()
                         .map_or(false, |ident| ident.to_string() == "service")
                 })
-                .is_some()
+                .map(|service| (field, service))
         })
         .enumerate()
-        .map(|(id, field)| (id, field.ident.as_ref().unwrap(), filter_attr(&field.attrs), parse_field(&field.ty)))
+        .map(|(id, (field, service))| (
+            id,
+            field.ident.as_ref().unwrap(),
+            filter_attr(&field.attrs),
+            parse_field(&field.ty)
+                .and_then(|ty| parse_service_attrs(service).map(|service| (ty, service)))
+                .and_then(|(ty, service)| match service.envelope {
+                    true => check_envelope(&ty).map(|_| (ty, service)),
+                    false => Ok((ty, service)),
+                }),
+        ))
     {
         match res {
-            Ok(ty) => parsed.push(Services {
+            Ok((ty, service)) => parsed.push(Services {
                 id,
                 ident,
                 attr: attr.map(|attr| quote!(#attr)).unwrap_or_default(),
                 upper: Ident::new(&ident.to_string().to_uppercase(), ident.span()),
                 ty,
+                service,
             }),
             Err(err) => return Err(err.into_compile_error()),
         }
@@ -146,6 +219,59 @@ fn parse(input: &ItemStruct) -> Result<Vec<Services>, TokenStream2> {
     Ok(parsed)
 }
 
+fn parse_routes(attrs: &[Attribute], servs: &Vec<Services>) -> Result<Vec<(usize, Vec<usize>)>, syn::Error> {
+    let find = |expr: &Expr| match expr {
+        Expr::Path(path) => path
+            .path
+            .get_ident()
+            .and_then(|ident| servs.iter().find(|serv| serv.ident == ident))
+            .map(|serv| serv.id)
+            .ok_or_else(|| syn::Error::new_spanned(expr, "Unknown target")),
+        _ => Err(syn::Error::new_spanned(expr, "Expected a target name")),
+    };
+
+    let mut routes = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("notifier")) {
+        let call = match syn::parse2(attr.tokens.clone()).map(|ExprParen { expr, .. }| *expr) {
+            Ok(Expr::Call(call)) => call,
+            _ => continue,
+        };
+        if !matches!(call.func.as_ref(), Expr::Path(path) if path.path.is_ident("allow")) {
+            return Err(syn::Error::new_spanned(call.func, "Unknown notifier attribute"));
+        }
+        for arg in call.args {
+            let (left, right) = match arg {
+                Expr::Assign(ExprAssign { left, right, .. }) => (left, right),
+                _ => return Err(syn::Error::new_spanned(arg, "Expected `source = [targets]`")),
+            };
+            let from = find(&left)?;
+            let to = match right.as_ref() {
+                Expr::Array(array) => array.elems.iter().map(find).collect::<Result<Vec<_>, _>>()?,
+                _ => return Err(syn::Error::new_spanned(right, "Expected an array of targets")),
+            };
+            if routes.iter().any(|(source, _)| *source == from) {
+                return Err(syn::Error::new_spanned(left, "Duplicate allow-list"));
+            }
+            routes.push((from, to));
+        }
+    }
+    Ok(routes)
+}
+
+fn parse_interceptors(input: &ItemStruct) -> Result<Option<&Ident>, syn::Error> {
+    let mut fields = input.fields.iter().filter(|field| {
+        field
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("interceptors"))
+    });
+    let ret = fields.next().map(|field| field.ident.as_ref().unwrap());
+    match fields.next() {
+        Some(field) => Err(syn::Error::new_spanned(field, "Only one interceptors field is allowed")),
+        None => Ok(ret),
+    }
+}
+
 fn targets(
     vis: &Visibility,
     crate_path: &TokenStream2,
@@ -446,7 +572,8 @@ fn service_get(
 fn notifier_senders(
     name: &Ident,
     crate_path: &TokenStream2,
-    servs: &Vec<Services>
+    servs: &Vec<Services>,
+    interceptors: Option<&Ident>,
 ) -> TokenStream2 {
         fn insert<'a>(
             map: &mut HashMap<&'a Type, Vec<(NotifTypes<'a>, &'a TokenStream2)>>,
@@ -464,14 +591,19 @@ fn notifier_senders(
 
         servs.iter().fold(
             HashMap::<&Type, Vec<(NotifTypes, &TokenStream2)>>::new(),
-            |mut map, Services { ident, ty, attr, .. }| {
+            |mut map, Services { ident, ty, attr, service, .. }| {
                 fn wrap<'a>(
                     map: &mut HashMap<&'a Type, Vec<(NotifTypes<'a>, &'a TokenStream2)>>,
                     ty: &'a FieldTypes,
                     ident: &'a Ident,
                     arr: bool,
+                    envelope: bool,
                     attr: &'a TokenStream2,
                 ) {
+                    let payload = |ty: &'a Type| match envelope {
+                        true => envelope_payload(ty).unwrap(),
+                        false => ty,
+                    };
                     let wrap = |ident, index| {
                         if arr {
                             NotifTypes::Array(ident, index)
@@ -480,18 +612,18 @@ fn notifier_senders(
                         }
                     };
                     match ty {
-                        FieldTypes::Once(ty, _) => insert(map, ty, wrap(ident, None), attr),
+                        FieldTypes::Once(ty, _) => insert(map, payload(ty), wrap(ident, None), attr),
                         FieldTypes::Tuple(vec) => vec
                             .into_iter()
                             .enumerate()
-                            .for_each(|(index, (ty, _))| insert(map, ty, wrap(ident, Some(index)), attr)),
+                            .for_each(|(index, (ty, _))| insert(map, payload(ty), wrap(ident, Some(index)), attr)),
                         FieldTypes::Array(_, _) => unreachable!(),
                     }
                 }
 
                 match ty {
-                    FieldTypes::Array(ty, _) => wrap(&mut map, ty, ident, true, attr),
-                    _ => wrap(&mut map, ty, ident, false, attr),
+                    FieldTypes::Array(ty, _) => wrap(&mut map, ty, ident, true, service.envelope, attr),
+                    _ => wrap(&mut map, ty, ident, false, service.envelope, attr),
                 }
                 map
             },
@@ -499,7 +631,7 @@ fn notifier_senders(
         .into_iter()
         .fold(TokenStream2::new(), |mut output, (ty, vec)| {
             let iters = vec.into_iter().fold(TokenStream2::new(), |mut output, (notif, attr)| {
-                let r#as = quote!( as &dyn #crate_path::DynamicService<#ty>);
+                let r#as = quote!( as &dyn #crate_path::DynamicSender<#ty>);
                 let preffix = match &notif {
                     NotifTypes::Once(_, Some(_)) | NotifTypes::Array(_, Some(_)) => {
                         quote!(&)
@@ -524,7 +656,7 @@ fn notifier_senders(
             });
             output.extend(quote!(
                 impl #crate_path::NotifierSenders<#ty> for #name {
-                    type Iter<'ch> = impl Iterator<Item = &'ch dyn DynamicService<#ty>> + Clone 
+                    type Iter<'ch> = impl Iterator<Item = &'ch dyn #crate_path::DynamicSender<#ty>> + Clone 
                         where 
                             #ty: 'ch,
                             Self: 'ch;
@@ -535,6 +667,31 @@ fn notifier_senders(
                     }
                 }
             ));
+            let intercept = match interceptors {
+                Some(ident) => quote!(
+                    fn intercept(
+                        &self,
+                        outgoing: &#crate_path::Outgoing<'_>,
+                        event: &mut #ty,
+                    ) -> #crate_path::Verdict {
+                        self.#ident.intercept(outgoing, event)
+                    }
+                ),
+                None => quote!(
+                    fn intercept(
+                        &self,
+                        _: &#crate_path::Outgoing<'_>,
+                        _: &mut #ty,
+                    ) -> #crate_path::Verdict {
+                        #crate_path::Verdict::Pass
+                    }
+                ),
+            };
+            output.extend(quote!(
+                impl #crate_path::NotifierInterceptors<#ty> for #name {
+                    #intercept
+                }
+            ));
             output
         })
     
@@ -562,10 +719,24 @@ fn notifier_impl(input: &ItemStruct) -> TokenStream2 {
 
     let targets = targets(vis, &crate_path, target, &parsed);
     let service_get = service_get(name, &crate_path, target, &parsed);
-    let notifier_senders = notifier_senders(name, &crate_path, &parsed);
+    let interceptors = match parse_interceptors(input) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error(),
+    };
+    let notifier_senders = notifier_senders(name, &crate_path, &parsed, interceptors);
+    let routes = match parse_routes(&input.attrs, &parsed) {
+        Ok(res) => res,
+        Err(err) => return err.into_compile_error(),
+    };
     let notifier = {
+        let routes = routes.iter().fold(TokenStream2::new(), |mut output, (from, to)| {
+            output.extend(quote!((#from, &[#(#to),*]),));
+            output
+        });
         quote!(
-            impl #crate_path ::Notifier for #name {}
+            impl #crate_path ::Notifier for #name {
+                const ROUTES: &'static [#crate_path::Route] = &[#routes];
+            }
         )
     };
     let aliases = {
@@ -603,28 +774,44 @@ fn notifier_impl(input: &ItemStruct) -> TokenStream2 {
         });
         let init = parsed.iter().fold(TokenStream2::new(), |
             mut output, 
-            Services { ident, upper, ty, attr, .. }
+            Services { ident, upper, ty, attr, service, .. }
         | {
+            let setup = |path: TokenStream2| {
+                let mut output = TokenStream2::new();
+                if let Some(overflow) = &service.overflow {
+                    output.extend(quote!(#path.set_overflow(#crate_path::Overflow::#overflow);));
+                }
+                output
+            };
             output.extend(match ty {
-                FieldTypes::Once(_, _) => quote!(#attr self.#ident.init(#target::#upper);),
+                FieldTypes::Once(_, _) => {
+                    let setup = setup(quote!(self.#ident));
+                    quote!(#attr { self.#ident.init(#target::#upper); #setup })
+                },
                 FieldTypes::Tuple(vec) => {
                     vec.iter().enumerate().fold(TokenStream2::new(), |mut output, (index, _)| {
                         let index = Literal::usize_unsuffixed(index);
+                        let setup = setup(quote!(self.#ident.#index));
                         output.extend(quote!(
                             #attr
-                            self.#ident.#index.init(#target::#upper);
+                            { self.#ident.#index.init(#target::#upper); #setup }
                         ));
                         output
                     })
                 },
                 FieldTypes::Array(ty, _) => {
                     let body = match ty.as_ref() {
-                        FieldTypes::Once(_, _) => quote!(#ident.init(id)),
+                        FieldTypes::Once(_, _) => {
+                            let setup = setup(quote!(#ident));
+                            quote!(#ident.init(id); #setup)
+                        },
                         FieldTypes::Tuple(vec) => {
                             vec.iter().enumerate().fold(TokenStream2::new(), |mut output, (index, _)| {
                                 let index = Literal::usize_unsuffixed(index);
+                                let setup = setup(quote!(#ident.#index));
                                 output.extend(quote!(
                                     #ident.#index.init(id);
+                                    #setup
                                 ));
                                 output
                             })
@@ -660,7 +847,7 @@ fn notifier_impl(input: &ItemStruct) -> TokenStream2 {
     )
 }
 
-#[proc_macro_derive(Notifier, attributes(service, notifier))]
+#[proc_macro_derive(Notifier, attributes(service, notifier, interceptors))]
 pub fn macro_body(input: TokenStream) -> TokenStream {
     match syn::parse(input).map(|input: ItemStruct| notifier_impl(&input)) {
         Ok(output) => TokenStream::from(output),
@@ -0,0 +1,89 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+pub use std::sync::mpsc::{RecvError, RecvTimeoutError, TryRecvError, TrySendError};
+
+struct Shared<T> {
+    queue: Mutex<VecDeque<T>>,
+    capacity: usize,
+    readable: Condvar,
+}
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, VecDeque<T>> {
+        self.queue.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+pub fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        capacity,
+        readable: Condvar::new(),
+    });
+    (Sender(shared.clone()), Receiver(shared))
+}
+
+pub struct Sender<T>(Arc<Shared<T>>);
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl<T> Sender<T> {
+    pub fn try_send(&self, event: T) -> Result<(), TrySendError<T>> {
+        let mut queue = self.0.lock();
+        if queue.len() >= self.0.capacity {
+            return Err(TrySendError::Full(event));
+        }
+        queue.push_back(event);
+        self.0.readable.notify_one();
+        Ok(())
+    }
+}
+
+pub struct Receiver<T>(Arc<Shared<T>>);
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl<T> Receiver<T> {
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        self.0.lock().pop_front().ok_or(TryRecvError::Empty)
+    }
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut queue = self.0.lock();
+        loop {
+            if let Some(event) = queue.pop_front() {
+                break Ok(event);
+            }
+            queue = self
+                .0
+                .readable
+                .wait(queue)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut queue = self.0.lock();
+        loop {
+            if let Some(event) = queue.pop_front() {
+                break Ok(event);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break Err(RecvTimeoutError::Timeout);
+            }
+            queue = self
+                .0
+                .readable
+                .wait_timeout(queue, remaining)
+                .unwrap_or_else(|err| err.into_inner())
+                .0;
+        }
+    }
+}
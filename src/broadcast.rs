@@ -0,0 +1,200 @@
+use super::*;
+
+struct Buffer<T, const N: usize, const R: usize> {
+    events: [Option<T>; N],
+    head: usize,
+    cursors: [Option<usize>; R],
+}
+impl<T: Clone, const N: usize, const R: usize> Buffer<T, N, R> {
+    fn new() -> Self {
+        Self {
+            events: core::array::from_fn(|_| None),
+            head: 0,
+            cursors: [None; R],
+        }
+    }
+    fn tail(&self) -> usize {
+        self.cursors
+            .iter()
+            .flatten()
+            .copied()
+            .min()
+            .unwrap_or(self.head)
+    }
+    fn trim(&mut self, from: usize) -> usize {
+        let to = self.tail();
+        for seq in from..to {
+            self.events[seq % N] = None;
+        }
+        to - from
+    }
+    fn subscribe(&mut self) -> Option<usize> {
+        let (subscriber, cursor) = self
+            .cursors
+            .iter_mut()
+            .enumerate()
+            .find(|(_, cursor)| cursor.is_none())?;
+        *cursor = Some(self.head);
+        Some(subscriber)
+    }
+    fn unsubscribe(&mut self, subscriber: usize) -> usize {
+        let tail = self.tail();
+        self.cursors[subscriber] = None;
+        self.trim(tail)
+    }
+    fn push(&mut self, event: T) -> Result<(), T> {
+        if self.cursors.iter().all(Option::is_none) {
+            return Err(event);
+        }
+        self.events[self.head % N] = Some(event);
+        self.head += 1;
+        Ok(())
+    }
+    fn pop(&mut self, subscriber: usize) -> Option<(T, usize)> {
+        let tail = self.tail();
+        let cursor = self.cursors[subscriber].as_mut()?;
+        if *cursor == self.head {
+            return None;
+        }
+        let event = self.events[*cursor % N].clone()?;
+        *cursor += 1;
+        Some((event, self.trim(tail)))
+    }
+    fn discard(&mut self) -> Option<T> {
+        let tail = self.tail();
+        if tail == self.head {
+            return None;
+        }
+        for cursor in self.cursors.iter_mut().flatten() {
+            if *cursor == tail {
+                *cursor += 1;
+            }
+        }
+        self.events[tail % N].take()
+    }
+}
+
+pub struct BroadcastService<T, const N: usize, const R: usize>(
+    Option<ID>,
+    prelude::Mutex<Buffer<T, N, R>>,
+    prelude::Channel<(), N>,
+    prelude::Mutex<State>,
+    [prelude::Channel<(), 1>; R],
+    Overflow,
+    prelude::Mutex<Metrics>,
+);
+impl<T: Clone, const N: usize, const R: usize> BroadcastService<T, N, R> {
+    pub fn init(&mut self, id: impl Into<ID>) {
+        self.0 = Some(id.into());
+    }
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.5 = overflow;
+    }
+    fn ring(&self) {
+        for doorbell in &self.4 {
+            let sender = prelude::sender(doorbell);
+            let _ = sender.try_send(());
+        }
+    }
+    fn free(&self, count: usize) {
+        for _ in 0..count {
+            private::DynamicSender::dequeued(self, 0);
+        }
+    }
+}
+impl<T: Clone, const N: usize, const R: usize> Default for BroadcastService<T, N, R> {
+    fn default() -> Self {
+        Self(
+            None,
+            prelude::Mutex::new(Buffer::new()),
+            prelude::Channel::new(),
+            prelude::Mutex::new(State::Inactive),
+            core::array::from_fn(|_| prelude::Channel::new()),
+            Overflow::default(),
+            prelude::Mutex::new(Metrics::default()),
+        )
+    }
+}
+impl<T, const N: usize, const R: usize> private::DynamicServiceId for BroadcastService<T, N, R> {
+    fn id(&self) -> &Option<ID> {
+        &self.0
+    }
+}
+impl<T, const N: usize, const R: usize> private::DynamicServiceState for BroadcastService<T, N, R> {
+    fn state(&self, call: &mut dyn FnMut(&mut State)) {
+        self.3.lock(call)
+    }
+}
+impl<T, const N: usize, const R: usize> private::DynamicServiceMetrics
+    for BroadcastService<T, N, R>
+{
+    fn metrics(&self, call: &mut dyn FnMut(&mut Metrics)) {
+        self.6.lock(call)
+    }
+}
+impl<T: Clone, const N: usize, const R: usize> private::DynamicService<T>
+    for BroadcastService<T, N, R>
+{
+    fn source(&self) -> private::Source<'_, T> {
+        self.subscription(0)
+    }
+
+    fn take(&self) -> Result<T, prelude::RecvError> {
+        Err(prelude::RECV_EMPTY)
+    }
+
+    fn subscribe(&self) -> Option<usize> {
+        let subscriber = self.1.lock(Buffer::subscribe)?;
+        let doorbell = prelude::receiver(&self.4[subscriber]);
+        let _ = doorbell.try_recv();
+        Some(subscriber)
+    }
+
+    fn unsubscribe(&self, subscriber: usize) {
+        let freed = self.1.lock(|buffer| buffer.unsubscribe(subscriber));
+        self.free(freed);
+    }
+
+    fn subscription(&self, subscriber: usize) -> private::Source<'_, T> {
+        private::Source::doorbell(&self.4[subscriber])
+    }
+
+    fn take_for(&self, subscriber: usize) -> Result<T, prelude::RecvError> {
+        let (event, freed) = self
+            .1
+            .lock(|buffer| buffer.pop(subscriber))
+            .ok_or(prelude::RECV_EMPTY)?;
+        self.free(freed);
+        Ok(event)
+    }
+}
+impl<T: Clone, const N: usize, const R: usize> private::DynamicSender<T>
+    for BroadcastService<T, N, R>
+{
+    fn slot_sender(&self, _priority: usize) -> prelude::Sender<'_, ()> {
+        prelude::sender(&self.2)
+    }
+
+    fn slot_receiver(&self, _priority: usize) -> prelude::Receiver<'_, ()> {
+        prelude::receiver(&self.2)
+    }
+
+    fn overflow(&self) -> Overflow {
+        self.5
+    }
+
+    fn discard(&self, _priority: usize) -> Option<T> {
+        self.1.lock(Buffer::discard)
+    }
+
+    fn push(&self, _from: &ID, _priority: usize, event: T) -> Delivery<T> {
+        match self.1.lock(|buffer| buffer.push(event)) {
+            Ok(()) => {
+                self.6.lock(Metrics::enqueued);
+                self.ring();
+                Delivery::Delivered
+            }
+            Err(event) => Delivery::Disconnected(event),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use core::{
+    marker::PhantomData,
     ops::{Deref, DerefMut},
     usize,
 };
@@ -22,7 +23,14 @@ where
     }
 
     pub fn sender(&self) -> Sender<'notif, Notif> {
-        Sender(self.id(), self.notifier)
+        Sender(self.id(), self.notifier, None, false)
+    }
+    pub fn route<const TO: usize, T: 'notif>(&self) -> Result<TypedSender<'notif, T>, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        let () = RouteCheck::<Notif, { ID }, TO>::ALLOWED;
+        self.sender().route_to([ID::new(TO)])
     }
     pub fn receiver<T>(&self) -> Receiver<'notif, T>
     where
@@ -41,6 +49,11 @@ where
     }
 }
 
+struct RouteCheck<Notif, const FROM: usize, const TO: usize>(PhantomData<Notif>);
+impl<Notif: Notifier, const FROM: usize, const TO: usize> RouteCheck<Notif, FROM, TO> {
+    const ALLOWED: () = assert!(allows(Notif::ROUTES, FROM, TO), "Route is not allowed");
+}
+
 pub struct Channels<'notif, const SIZE: usize, Notif, Targets, const ID: usize> {
     array: [Channel<'notif, Notif, Targets, { ID }>; SIZE],
 }
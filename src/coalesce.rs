@@ -0,0 +1,162 @@
+use super::*;
+
+pub trait Coalesce {
+    type Key: PartialEq;
+    fn key(&self) -> Self::Key;
+}
+
+struct Queue<T, const N: usize>([Option<T>; N], usize);
+impl<T: Coalesce, const N: usize> Queue<T, N> {
+    fn new() -> Self {
+        Self(core::array::from_fn(|_| None), 0)
+    }
+    fn replace(&mut self, event: T) -> Result<(), T> {
+        let key = event.key();
+        match self.0[..self.1]
+            .iter_mut()
+            .flatten()
+            .find(|queued| queued.key() == key)
+        {
+            Some(queued) => {
+                *queued = event;
+                Ok(())
+            }
+            None => Err(event),
+        }
+    }
+    fn push(&mut self, event: T) -> Result<(), T> {
+        match self.0.get_mut(self.1) {
+            Some(slot) => {
+                *slot = Some(event);
+                self.1 += 1;
+                Ok(())
+            }
+            None => Err(event),
+        }
+    }
+    fn pop(&mut self) -> Option<T> {
+        if self.1 == 0 {
+            return None;
+        }
+        let event = self.0[0].take();
+        self.0[..self.1].rotate_left(1);
+        self.1 -= 1;
+        event
+    }
+}
+
+pub struct CoalesceService<T: Coalesce, const N: usize>(
+    Option<ID>,
+    prelude::Mutex<Queue<T, N>>,
+    prelude::Channel<(), N>,
+    prelude::Mutex<State>,
+    prelude::Channel<(), 1>,
+    Overflow,
+    prelude::Mutex<Metrics>,
+);
+impl<T: Coalesce, const N: usize> CoalesceService<T, N> {
+    pub fn init(&mut self, id: impl Into<ID>) {
+        self.0 = Some(id.into());
+    }
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.5 = overflow;
+    }
+    fn ring(&self) {
+        let sender = prelude::sender(&self.4);
+        let _ = sender.try_send(());
+    }
+}
+impl<T: Coalesce, const N: usize> Default for CoalesceService<T, N> {
+    fn default() -> Self {
+        Self(
+            None,
+            prelude::Mutex::new(Queue::new()),
+            prelude::Channel::new(),
+            prelude::Mutex::new(State::Inactive),
+            prelude::Channel::new(),
+            Overflow::default(),
+            prelude::Mutex::new(Metrics::default()),
+        )
+    }
+}
+impl<T: Coalesce, const N: usize> private::DynamicServiceId for CoalesceService<T, N> {
+    fn id(&self) -> &Option<ID> {
+        &self.0
+    }
+}
+impl<T: Coalesce, const N: usize> private::DynamicServiceState for CoalesceService<T, N> {
+    fn state(&self, call: &mut dyn FnMut(&mut State)) {
+        self.3.lock(call)
+    }
+}
+impl<T: Coalesce, const N: usize> private::DynamicServiceMetrics for CoalesceService<T, N> {
+    fn metrics(&self, call: &mut dyn FnMut(&mut Metrics)) {
+        self.6.lock(call)
+    }
+}
+impl<T: Coalesce, const N: usize> private::DynamicService<T> for CoalesceService<T, N> {
+    fn source(&self) -> private::Source<'_, T> {
+        private::Source::doorbell(&self.4)
+    }
+
+    fn take(&self) -> Result<T, prelude::RecvError> {
+        let event = self.1.lock(Queue::pop).ok_or(prelude::RECV_EMPTY)?;
+        private::DynamicSender::dequeued(self, 0);
+        self.ring();
+        Ok(event)
+    }
+}
+impl<T: Coalesce, const N: usize> private::DynamicSender<T> for CoalesceService<T, N> {
+    fn slot_sender(&self, _priority: usize) -> prelude::Sender<'_, ()> {
+        prelude::sender(&self.2)
+    }
+
+    fn slot_receiver(&self, _priority: usize) -> prelude::Receiver<'_, ()> {
+        prelude::receiver(&self.2)
+    }
+
+    fn overflow(&self) -> Overflow {
+        self.5
+    }
+
+    fn discard(&self, _priority: usize) -> Option<T> {
+        self.1.lock(Queue::pop)
+    }
+
+    fn push(&self, _from: &ID, priority: usize, event: T) -> Delivery<T> {
+        let res = self.1.lock(|queue| match queue.replace(event) {
+            Ok(()) => Ok(true),
+            Err(event) => queue.push(event).map(|_| false),
+        });
+        match res {
+            Ok(replaced) => {
+                match replaced {
+                    true => self.release(priority),
+                    false => self.6.lock(Metrics::enqueued),
+                }
+                self.ring();
+                Delivery::Delivered
+            }
+            Err(event) => Delivery::Full(event),
+        }
+    }
+
+    fn try_send(&self, from: &ID, priority: usize, event: T) -> Delivery<T> {
+        let event = match self.1.lock(|queue| queue.replace(event)) {
+            Ok(()) => {
+                self.ring();
+                self.record(&Delivery::Delivered);
+                return Delivery::Delivered;
+            }
+            Err(event) => event,
+        };
+        match self.reserve(priority) {
+            true => self.commit(from, priority, event),
+            false => {
+                let delivery = Delivery::Full(event);
+                self.record(&delivery);
+                delivery
+            }
+        }
+    }
+}
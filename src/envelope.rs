@@ -0,0 +1,13 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Envelope<T> {
+    pub from: ID,
+    pub seq: usize,
+    pub payload: T,
+}
+impl<T> Envelope<T> {
+    pub fn into_payload(self) -> T {
+        self.payload
+    }
+}
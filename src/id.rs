@@ -1,6 +1,7 @@
 pub static INCORRECT_INDEX: &str = "Incorrect channel index";
+pub static TOO_MANY_SUBSCRIBERS: &str = "Too many subscribers";
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ID(
     pub(super) usize,
     pub(super) Option<usize>,
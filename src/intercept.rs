@@ -0,0 +1,81 @@
+use super::*;
+use core::any::Any;
+
+pub struct Outgoing<'a> {
+    pub from: &'a ID,
+    pub to: &'a ID,
+    pub type_name: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Veto,
+    Duplicate(usize),
+}
+
+pub trait Interceptor: Sync {
+    fn intercept(&self, outgoing: &Outgoing<'_>, event: &mut dyn Any) -> Verdict;
+}
+
+pub trait NotifierInterceptors<T> {
+    fn intercept(&self, outgoing: &Outgoing<'_>, event: &mut T) -> Verdict;
+}
+
+pub struct Interceptors<const S: usize>(prelude::Mutex<[Option<&'static dyn Interceptor>; S]>);
+impl<const S: usize> Interceptors<S> {
+    pub fn register(&self, interceptor: &'static dyn Interceptor) -> bool {
+        self.0
+            .lock(|chain| match chain.iter_mut().find(|slot| slot.is_none()) {
+                Some(slot) => {
+                    *slot = Some(interceptor);
+                    true
+                }
+                None => false,
+            })
+    }
+    pub fn clear(&self) {
+        self.0.lock(|chain| *chain = [None; S])
+    }
+    pub fn intercept<T: 'static>(&self, outgoing: &Outgoing<'_>, event: &mut T) -> Verdict {
+        let chain = self.0.lock(|chain| *chain);
+        let mut copies = 0;
+        for interceptor in chain.iter().flatten() {
+            match interceptor.intercept(outgoing, event) {
+                Verdict::Pass => (),
+                Verdict::Veto => return Verdict::Veto,
+                Verdict::Duplicate(count) => copies += count,
+            }
+        }
+        match copies {
+            0 => Verdict::Pass,
+            copies => Verdict::Duplicate(copies),
+        }
+    }
+}
+impl<const S: usize> Default for Interceptors<S> {
+    fn default() -> Self {
+        Self(prelude::Mutex::new([None; S]))
+    }
+}
+
+pub(super) fn intercept<T>(
+    interceptors: &dyn NotifierInterceptors<T>,
+    from: &ID,
+    to: &ID,
+    mut event: T,
+) -> Result<(T, usize), Delivery<T>> {
+    let outgoing = Outgoing {
+        from,
+        to,
+        type_name: core::any::type_name::<T>(),
+    };
+    match interceptors.intercept(&outgoing, &mut event) {
+        Verdict::Pass => Ok((event, 0)),
+        Verdict::Veto => {
+            log::debug!("Vetoed sending to {to}");
+            Err(Delivery::Vetoed(event))
+        }
+        Verdict::Duplicate(copies) => Ok((event, copies)),
+    }
+}
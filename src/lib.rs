@@ -1,17 +1,55 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 pub use target_notifier_proc::Notifier;
 
+pub use broadcast::*;
 pub use channel::*;
+pub use coalesce::*;
+pub use envelope::*;
 pub use id::*;
+pub use intercept::*;
+pub use limiter::*;
+#[cfg(any(feature = "async-channel", feature = "async-std"))]
+pub use owned::*;
+pub use permit::*;
+pub use priority::*;
 pub use receiver::*;
+pub use report::*;
+pub use request::*;
+pub use route::*;
+#[cfg(not(feature = "std"))]
+pub use schedule::*;
 pub use sender::*;
 pub use service::*;
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
+pub use shared::*;
+pub use timer::*;
 pub use traits::*;
+pub use typed::*;
 
+#[cfg(feature = "std")]
+mod blocking;
+mod broadcast;
 mod channel;
+mod coalesce;
+mod envelope;
 mod id;
+mod intercept;
+mod limiter;
+#[cfg(any(feature = "async-channel", feature = "async-std"))]
+mod owned;
+mod permit;
 mod prelude;
+mod priority;
 mod receiver;
+mod report;
+mod request;
+mod route;
+#[cfg(not(feature = "std"))]
+mod schedule;
 mod sender;
 mod service;
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
+mod shared;
+mod timer;
 mod traits;
+mod typed;
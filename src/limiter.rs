@@ -0,0 +1,120 @@
+use super::*;
+use core::ops::Add;
+
+pub trait RateLimit: Sync {
+    fn admit(&self, target: &ID) -> bool;
+    /// Like [`RateLimit::admit`], but takes no token and counts no drop.
+    fn check(&self, target: &ID) -> bool;
+}
+
+struct Entry<Tm: Timer> {
+    target: ID,
+    rate: Option<(u32, Tm::Duration)>,
+    debounce: Option<Tm::Duration>,
+    next: Option<Tm::Instant>,
+    dropped: usize,
+}
+
+pub struct Limiter<'t, Tm: Timer, const S: usize>(&'t Tm, prelude::Mutex<[Option<Entry<Tm>>; S]>);
+impl<'t, Tm, const S: usize> Limiter<'t, Tm, S>
+where
+    Tm: Timer,
+    Tm::Instant: Ord + Add<Tm::Duration, Output = Tm::Instant>,
+    Tm::Duration: Copy,
+{
+    pub fn new(timer: &'t Tm) -> Self {
+        Self(timer, prelude::Mutex::new(core::array::from_fn(|_| None)))
+    }
+    pub fn set_rate(&self, target: impl Into<ID>, burst: u32, period: Tm::Duration) -> bool {
+        self.entry(target.into(), true, |entry| {
+            entry.rate = Some((burst.max(1), period));
+            entry.next = None;
+        })
+        .is_some()
+    }
+    pub fn set_debounce(&self, target: impl Into<ID>, window: Tm::Duration) -> bool {
+        self.entry(target.into(), true, |entry| entry.debounce = Some(window))
+            .is_some()
+    }
+    pub fn dropped(&self, target: impl Into<ID>) -> usize {
+        self.entry(target.into(), false, |entry| entry.dropped)
+            .unwrap_or_default()
+    }
+    pub fn reset_dropped(&self, target: impl Into<ID>) -> usize {
+        self.entry(target.into(), false, |entry| {
+            core::mem::take(&mut entry.dropped)
+        })
+        .unwrap_or_default()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(super) fn debounce(&self, target: &ID) -> Option<Tm::Duration> {
+        self.entry(*target, false, |entry| entry.debounce).flatten()
+    }
+    #[cfg(not(feature = "std"))]
+    pub(super) fn debounced(&self, target: &ID) {
+        self.entry(*target, false, |entry| entry.dropped += 1);
+    }
+
+    fn pass(&self, target: &ID, spend: bool) -> bool {
+        let now = self.0.now();
+        self.entry(*target, false, |entry| {
+            let (burst, period) = match entry.rate {
+                Some(rate) => rate,
+                None => return true,
+            };
+            let next = entry.next.map_or(now, |next| next.max(now));
+            let limit = (1..burst).fold(now, |limit, _| limit + period);
+            if next > limit {
+                entry.dropped += usize::from(spend);
+                return false;
+            }
+            if spend {
+                entry.next = Some(next + period);
+            }
+            true
+        })
+        .unwrap_or(true)
+    }
+
+    fn entry<R>(
+        &self,
+        target: ID,
+        insert: bool,
+        call: impl FnOnce(&mut Entry<Tm>) -> R,
+    ) -> Option<R> {
+        self.1.lock(|entries| {
+            if let Some(entry) = entries
+                .iter_mut()
+                .flatten()
+                .find(|entry| target.eq_target(&entry.target))
+            {
+                return Some(call(entry));
+            }
+            let slot = entries
+                .iter_mut()
+                .find(|entry| entry.is_none())
+                .filter(|_| insert)?;
+            Some(call(slot.insert(Entry {
+                target,
+                rate: None,
+                debounce: None,
+                next: None,
+                dropped: 0,
+            })))
+        })
+    }
+}
+impl<'t, Tm, const S: usize> RateLimit for Limiter<'t, Tm, S>
+where
+    Tm: Timer + Sync,
+    Tm::Instant: Ord + Add<Tm::Duration, Output = Tm::Instant> + Send,
+    Tm::Duration: Copy + Send,
+{
+    fn admit(&self, target: &ID) -> bool {
+        self.pass(target, true)
+    }
+    fn check(&self, target: &ID) -> bool {
+        self.pass(target, false)
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+use core::{future::Future, marker::PhantomData};
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+pub struct OwnedSender<Notif>(Arc<Notif>, ID, Option<&'static dyn RateLimit>, bool);
+impl<Notif> Clone for OwnedSender<Notif> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1, self.2, self.3)
+    }
+}
+impl<Notif: Notifier> OwnedSender<Notif> {
+    pub fn new(notif: Arc<Notif>, target: impl Into<ID>) -> Self {
+        Self(notif, target.into(), None, false)
+    }
+
+    pub fn id(&self) -> ID {
+        self.1
+    }
+
+    pub fn with_limiter(mut self, limiter: &'static dyn RateLimit) -> Self {
+        self.2 = Some(limiter);
+        self
+    }
+
+    /// See [`Sender::with_loopback`].
+    pub fn with_loopback(mut self, loopback: bool) -> Self {
+        self.3 = loopback;
+        self
+    }
+
+    pub fn loopback(&self) -> bool {
+        self.3
+    }
+
+    pub fn notifier(&self) -> &Arc<Notif> {
+        &self.0
+    }
+
+    pub fn sender(&self) -> Sender<'_, Notif> {
+        Sender(self.1, &self.0, self.2, self.3)
+    }
+
+    #[inline]
+    pub fn for_type<'s, T: 's>(&'s self) -> Result<TypedSender<'s, T>, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        self.sender().for_type()
+    }
+
+    #[inline]
+    pub fn route_to<'s, Tg, T: 's, const S: usize>(
+        &'s self,
+        targets: [Tg; S],
+    ) -> Result<TypedSender<'s, T>, Error<T>>
+    where
+        Tg: Copy,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        self.sender().route_to(targets)
+    }
+}
+
+pub struct OwnedReceiver<Notif: ServiceGet<T>, T>(Arc<Notif>, ID, usize, PhantomData<fn() -> T>);
+impl<Notif: ServiceGet<T>, T> OwnedReceiver<Notif, T> {
+    /// Returns `None` when `target` is unknown or its service has no free
+    /// subscriber slot.
+    pub fn new(notif: Arc<Notif>, target: impl Into<ID>) -> Option<Self> {
+        let id = notif.get(target)?.id().expect("Bad id");
+        Self::subscribe(notif, id)
+    }
+
+    /// Returns `None` instead of panicking when the service has no free
+    /// subscriber slot.
+    pub fn try_clone(&self) -> Option<Self> {
+        Self::subscribe(self.0.clone(), self.1)
+    }
+
+    /// Resolves the service on every poll instead of holding it across
+    /// awaits, so the future is `Send` whenever the handle is.
+    pub fn recv(&mut self) -> impl Future<Output = T> + '_ {
+        let this = &*self;
+        let mut source = this.field().subscription(this.2);
+        core::future::poll_fn(move |cx| {
+            receiver::poll_recv(&mut source, this.field(), this.2, cx).map(Option::unwrap)
+        })
+    }
+
+    pub fn try_recv(&mut self) -> Result<T, prelude::RecvError> {
+        receiver::try_recv(self.field(), self.2)
+    }
+
+    pub async fn recv_timeout<Tm: Timer>(
+        &mut self,
+        timer: &Tm,
+        after: Tm::Duration,
+    ) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(timer, timer.deadline(after)).await
+    }
+
+    pub async fn recv_deadline<Tm: Timer>(
+        &mut self,
+        timer: &Tm,
+        deadline: Tm::Instant,
+    ) -> Result<T, RecvTimeoutError> {
+        with_deadline(timer, deadline, self.recv())
+            .await
+            .ok_or(RecvTimeoutError::Timeout)
+    }
+
+    pub fn id(&self) -> &ID {
+        &self.1
+    }
+
+    pub fn target<Target: From<ID>>(&self) -> Target {
+        self.1.into()
+    }
+
+    pub fn notifier(&self) -> &Arc<Notif> {
+        &self.0
+    }
+
+    fn field(&self) -> &dyn DynamicService<T> {
+        self.0.get(self.1).expect(INCORRECT_INDEX)
+    }
+
+    fn subscribe(notif: Arc<Notif>, id: ID) -> Option<Self> {
+        let field = notif.get(id).expect(INCORRECT_INDEX);
+        let subscriber = field.subscribe()?;
+        field.state(&mut |state| state.incr());
+        Some(Self(notif, id, subscriber, PhantomData))
+    }
+}
+/// # Panics
+///
+/// Panics when the service has no free subscriber slot. Use
+/// [`OwnedReceiver::try_clone`] where the subscriber limit can be reached.
+impl<Notif: ServiceGet<T>, T> Clone for OwnedReceiver<Notif, T> {
+    fn clone(&self) -> Self {
+        self.try_clone().expect(TOO_MANY_SUBSCRIBERS)
+    }
+}
+impl<Notif: ServiceGet<T>, T> Drop for OwnedReceiver<Notif, T> {
+    fn drop(&mut self) {
+        receiver::release(self.field(), self.2)
+    }
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+pub struct Permit<'ch, T>(
+    &'ch dyn DynamicSender<T>,
+    ID,
+    usize,
+    &'ch dyn NotifierInterceptors<T>,
+);
+impl<'ch, T> Permit<'ch, T> {
+    pub(super) fn new(
+        field: &'ch dyn DynamicSender<T>,
+        from: ID,
+        priority: usize,
+        interceptors: &'ch dyn NotifierInterceptors<T>,
+    ) -> Option<Self> {
+        field
+            .reserve(priority)
+            .then(|| Self(field, from, priority, interceptors))
+    }
+    pub(super) fn try_new(
+        field: &'ch dyn DynamicSender<T>,
+        from: ID,
+        priority: usize,
+        interceptors: &'ch dyn NotifierInterceptors<T>,
+    ) -> Option<Self> {
+        field
+            .try_reserve(priority)
+            .then(|| Self(field, from, priority, interceptors))
+    }
+    pub fn id(&self) -> Option<&ID> {
+        self.0.id().as_ref()
+    }
+    pub fn send(self, event: T) -> Delivery<T>
+    where
+        T: Clone,
+    {
+        let to = self.0.id().expect("Bad id");
+        match intercept(self.3, &self.1, &to, event) {
+            Ok((event, copies)) => {
+                duplicate(&self.1, &to, self.0, self.2, &event, copies);
+                self.commit(event)
+            }
+            Err(vetoed) => vetoed,
+        }
+    }
+    pub(super) fn commit(self, event: T) -> Delivery<T> {
+        let (field, from, priority) = (self.0, self.1, self.2);
+        core::mem::forget(self);
+        field.commit(&from, priority, event)
+    }
+}
+impl<'ch, T> Drop for Permit<'ch, T> {
+    fn drop(&mut self) {
+        self.0.release(self.2)
+    }
+}
@@ -1,21 +1,38 @@
+#[cfg(feature = "std")]
+use super::blocking as channel;
 #[cfg(feature = "async-channel")]
 use async_channel as channel;
 #[cfg(feature = "async-std")]
 use async_std::channel;
-#[cfg(feature = "std")]
-use std::sync::mpsc as channel;
 
-#[cfg(feature = "std")]
-pub use channel::{RecvError, SendError};
-#[cfg(any(feature = "async-channel", feature = "async-std"))]
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
 pub use channel::{TryRecvError as RecvError, TrySendError as SendError};
 
+pub(super) const RECV_EMPTY: RecvError = RecvError::Empty;
+
 #[cfg(feature = "async-std")]
-use async_std::sync::Arc;
+pub(super) use async_std::sync::Arc;
 #[cfg(feature = "async-channel")]
-use hybrid_rc::Arc;
+pub(super) use hybrid_rc::Arc;
 #[cfg(feature = "std")]
-use std::sync::Arc;
+pub(super) use std::sync::Arc;
+
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
+extern crate alloc;
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
+pub(super) use alloc::vec::Vec;
+#[cfg(feature = "embassy")]
+pub(super) type Vec<T> = heapless::Vec<T, { super::REPORT_CAPACITY }>;
+
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
+pub(super) fn push<T>(vec: &mut Vec<T>, item: T) -> Result<(), T> {
+    vec.push(item);
+    Ok(())
+}
+#[cfg(feature = "embassy")]
+pub(super) fn push<T>(vec: &mut Vec<T>, item: T) -> Result<(), T> {
+    vec.push(item)
+}
 
 #[cfg(any(feature = "async-channel", feature = "async-std"))]
 use parking_lot as mutex;
@@ -33,22 +50,18 @@ pub use embassy_sync::channel::{
 #[cfg(feature = "embassy")]
 use core::cell::RefCell;
 
-use crate::State;
-use core::ops::Deref;
+use core::ops::DerefMut;
 
 #[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
 pub(super) type Sender<'ch, T> = channel::Sender<T>;
 #[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
 pub(super) type Receiver<'ch, T> = channel::Receiver<T>;
 #[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
-pub(super) struct Channel<T, const N: usize>(Sender<'static, T>, Receiver<'static, T>);
+pub struct Channel<T, const N: usize>(Sender<'static, T>, Receiver<'static, T>);
 #[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
 impl<T, const N: usize> Channel<T, N> {
     pub(super) fn new() -> Self {
-        #[cfg(any(feature = "async-channel", feature = "async-std"))]
         let ch = channel::bounded(N);
-        #[cfg(feature = "std")]
-        let ch = channel::channel();
         Self(ch.0, ch.1)
     }
     pub(super) fn sender(&self) -> Sender<'_, T> {
@@ -59,31 +72,72 @@ impl<T, const N: usize> Channel<T, N> {
     }
 }
 #[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
-type MutexServiceState = Arc<mutex::Mutex<State>>;
+pub(super) fn sender<T, const N: usize>(channel: &Channel<T, N>) -> Sender<'_, T> {
+    channel.sender()
+}
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
+pub(super) fn receiver<T, const N: usize>(channel: &Channel<T, N>) -> Receiver<'_, T> {
+    channel.receiver()
+}
+#[cfg(any(feature = "async-channel", feature = "async-std"))]
+pub(super) async fn send<T>(sender: &Sender<'_, T>, event: T) -> Result<(), SendError<T>> {
+    sender
+        .send(event)
+        .await
+        .map_err(|channel::SendError(event)| SendError::Closed(event))
+}
+#[cfg(any(feature = "async-channel", feature = "async-std"))]
+pub(super) async fn recv<T>(receiver: &Receiver<'_, T>) -> Option<T> {
+    receiver.recv().await.ok()
+}
+#[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
+type MutexServiceState<T> = Arc<mutex::Mutex<T>>;
 
 #[cfg(feature = "embassy")]
 pub(super) type Channel<T, const N: usize> = channel::Channel<CriticalSectionRawMutex, T, N>;
 #[cfg(feature = "embassy")]
-type MutexServiceState = CriticalSectionMutex<RefCell<State>>;
+pub(super) fn sender<T, const N: usize>(channel: &Channel<T, N>) -> Sender<'_, T> {
+    channel.sender().into()
+}
+#[cfg(feature = "embassy")]
+pub(super) fn receiver<T, const N: usize>(channel: &Channel<T, N>) -> Receiver<'_, T> {
+    channel.receiver().into()
+}
+#[cfg(feature = "embassy")]
+pub(super) async fn send<T>(sender: &Sender<'_, T>, event: T) -> Result<(), SendError<T>> {
+    sender.send(event).await;
+    Ok(())
+}
+#[cfg(feature = "embassy")]
+pub(super) async fn recv<T>(receiver: &Receiver<'_, T>) -> Option<T> {
+    Some(receiver.recv().await)
+}
+#[cfg(feature = "embassy")]
+type MutexServiceState<T> = CriticalSectionMutex<RefCell<T>>;
 
-pub(super) struct Mutex(MutexServiceState);
-impl Mutex {
-    pub(super) fn new() -> Self {
-        Self(Self::new_mutex())
+pub(super) struct Mutex<T>(MutexServiceState<T>);
+impl<T> Mutex<T> {
+    pub(super) fn new(value: T) -> Self {
+        Self(Self::new_mutex(value))
     }
     #[cfg(feature = "embassy")]
-    fn new_mutex() -> MutexServiceState {
-        MutexServiceState::new(RefCell::new(State::Inactive))
+    fn new_mutex(value: T) -> MutexServiceState<T> {
+        MutexServiceState::new(RefCell::new(value))
     }
     #[cfg(any(feature = "async-channel", feature = "async-std", feature = "std"))]
-    fn new_mutex() -> MutexServiceState {
-        MutexServiceState::new(mutex::Mutex::new(State::Inactive))
+    fn new_mutex(value: T) -> MutexServiceState<T> {
+        MutexServiceState::new(mutex::Mutex::new(value))
     }
-}
-impl Deref for Mutex {
-    type Target = MutexServiceState;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    #[cfg(feature = "embassy")]
+    pub(super) fn lock<R>(&self, call: impl FnOnce(&mut T) -> R) -> R {
+        self.0.lock(|cell| call(cell.borrow_mut().deref_mut()))
+    }
+    #[cfg(any(feature = "async-channel", feature = "async-std"))]
+    pub(super) fn lock<R>(&self, call: impl FnOnce(&mut T) -> R) -> R {
+        call(self.0.lock().deref_mut())
+    }
+    #[cfg(feature = "std")]
+    pub(super) fn lock<R>(&self, call: impl FnOnce(&mut T) -> R) -> R {
+        call(self.0.lock().unwrap().deref_mut())
     }
 }
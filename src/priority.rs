@@ -0,0 +1,102 @@
+use super::*;
+
+pub struct PriorityService<T, const N: usize, const L: usize>(
+    Option<ID>,
+    [prelude::Channel<T, N>; L],
+    [prelude::Channel<(), N>; L],
+    prelude::Mutex<State>,
+    prelude::Channel<(), 1>,
+    Overflow,
+    prelude::Mutex<Metrics>,
+);
+impl<T, const N: usize, const L: usize> PriorityService<T, N, L> {
+    pub fn init(&mut self, id: impl Into<ID>) {
+        self.0 = Some(id.into());
+    }
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.5 = overflow;
+    }
+    fn lane(priority: usize) -> usize {
+        priority.min(L - 1)
+    }
+    fn ring(&self) {
+        let sender = prelude::sender(&self.4);
+        let _ = sender.try_send(());
+    }
+}
+impl<T, const N: usize, const L: usize> Default for PriorityService<T, N, L> {
+    fn default() -> Self {
+        Self(
+            None,
+            core::array::from_fn(|_| prelude::Channel::new()),
+            core::array::from_fn(|_| prelude::Channel::new()),
+            prelude::Mutex::new(State::Inactive),
+            prelude::Channel::new(),
+            Overflow::default(),
+            prelude::Mutex::new(Metrics::default()),
+        )
+    }
+}
+impl<T, const N: usize, const L: usize> private::DynamicServiceId for PriorityService<T, N, L> {
+    fn id(&self) -> &Option<ID> {
+        &self.0
+    }
+}
+impl<T, const N: usize, const L: usize> private::DynamicServiceState for PriorityService<T, N, L> {
+    fn state(&self, call: &mut dyn FnMut(&mut State)) {
+        self.3.lock(call)
+    }
+}
+impl<T, const N: usize, const L: usize> private::DynamicServiceMetrics
+    for PriorityService<T, N, L>
+{
+    fn metrics(&self, call: &mut dyn FnMut(&mut Metrics)) {
+        self.6.lock(call)
+    }
+}
+impl<T, const N: usize, const L: usize> private::DynamicService<T> for PriorityService<T, N, L> {
+    fn source(&self) -> private::Source<'_, T> {
+        private::Source::doorbell(&self.4)
+    }
+
+    fn take(&self) -> Result<T, prelude::RecvError> {
+        for lane in (0..L).rev() {
+            let receiver = prelude::receiver(&self.1[lane]);
+            if let Ok(event) = receiver.try_recv() {
+                private::DynamicSender::dequeued(self, lane);
+                self.ring();
+                return Ok(event);
+            }
+        }
+        Err(prelude::RECV_EMPTY)
+    }
+}
+impl<T, const N: usize, const L: usize> private::DynamicSender<T> for PriorityService<T, N, L> {
+    fn slot_sender(&self, priority: usize) -> prelude::Sender<'_, ()> {
+        prelude::sender(&self.2[Self::lane(priority)])
+    }
+
+    fn slot_receiver(&self, priority: usize) -> prelude::Receiver<'_, ()> {
+        prelude::receiver(&self.2[Self::lane(priority)])
+    }
+
+    fn overflow(&self) -> Overflow {
+        self.5
+    }
+
+    fn discard(&self, priority: usize) -> Option<T> {
+        let receiver = prelude::receiver(&self.1[Self::lane(priority)]);
+        receiver.try_recv().ok()
+    }
+
+    fn push(&self, _from: &ID, priority: usize, event: T) -> Delivery<T> {
+        let sender = prelude::sender(&self.1[Self::lane(priority)]);
+        let res = sender.try_send(event);
+        let delivery = Delivery::from(res);
+        if delivery.is_delivered() {
+            self.6.lock(Metrics::enqueued);
+            self.ring();
+        }
+        delivery
+    }
+}
@@ -1,28 +1,84 @@
 use super::*;
+#[cfg(not(feature = "std"))]
 use core::future::Future;
+#[cfg(feature = "futures-util")]
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(feature = "futures-util")]
+use futures_util::Stream;
 
-pub struct Receiver<'ch, T>(prelude::Receiver<'ch, T>, &'ch dyn DynamicService<T>);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    Timeout,
+    Disconnected,
+}
+#[cfg(feature = "std")]
+impl From<std::sync::mpsc::RecvTimeoutError> for RecvTimeoutError {
+    fn from(err: std::sync::mpsc::RecvTimeoutError) -> Self {
+        match err {
+            std::sync::mpsc::RecvTimeoutError::Timeout => Self::Timeout,
+            std::sync::mpsc::RecvTimeoutError::Disconnected => Self::Disconnected,
+        }
+    }
+}
+
+pub struct Receiver<'ch, T>(
+    pub(super) private::Source<'ch, T>,
+    pub(super) &'ch dyn DynamicService<T>,
+    pub(super) usize,
+);
 impl<'ch, T> Receiver<'ch, T> {
     pub(super) fn new(field: &'ch dyn DynamicService<T>) -> Self {
+        Self::try_new(field).expect(TOO_MANY_SUBSCRIBERS)
+    }
+    pub(super) fn try_new(field: &'ch dyn DynamicService<T>) -> Option<Self> {
+        let subscriber = field.subscribe()?;
         field.state(&mut |state| state.incr());
-        Self(field.receiver(), field)
+        Some(Self(field.subscription(subscriber), field, subscriber))
     }
-    #[cfg(any(feature = "async-channel", feature = "async-std"))]
-    pub fn recv(&mut self) -> impl Future<Output = T> + '_ {
-        use futures_util::FutureExt;
-        self.0.recv().map(Result::unwrap)
+    /// Returns `None` instead of panicking when the service has no free
+    /// subscriber slot, e.g. a `BroadcastService<_, _, R>` with `R` receivers.
+    pub fn try_clone(&self) -> Option<Self> {
+        Self::try_new(self.1)
     }
-    #[cfg(feature = "embassy")]
+    #[cfg(not(feature = "std"))]
     pub fn recv(&mut self) -> impl Future<Output = T> + '_ {
-        self.0.recv()
+        recv(&self.0, self.1, self.2)
+    }
+    #[cfg(feature = "std")]
+    pub fn recv(&mut self) -> T {
+        recv(&self.0, self.1, self.2)
     }
-    #[cfg(not(feature = "std"))]
     pub fn try_recv(&mut self) -> Result<T, prelude::RecvError> {
-        self.0.try_recv()
+        try_recv(self.1, self.2)
+    }
+    #[cfg(not(feature = "std"))]
+    pub async fn recv_timeout<Tm: Timer>(
+        &mut self,
+        timer: &Tm,
+        after: Tm::Duration,
+    ) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(timer, timer.deadline(after)).await
+    }
+    #[cfg(not(feature = "std"))]
+    pub async fn recv_deadline<Tm: Timer>(
+        &mut self,
+        timer: &Tm,
+        deadline: Tm::Instant,
+    ) -> Result<T, RecvTimeoutError> {
+        with_deadline(timer, deadline, recv(&self.0, self.1, self.2))
+            .await
+            .ok_or(RecvTimeoutError::Timeout)
     }
     #[cfg(feature = "std")]
-    pub fn try_recv(&mut self) -> Result<T, prelude::RecvError> {
-        self.0.recv()
+    pub fn recv_timeout(&mut self, timeout: std::time::Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(std::time::Instant::now() + timeout)
+    }
+    #[cfg(feature = "std")]
+    pub fn recv_deadline(&mut self, deadline: std::time::Instant) -> Result<T, RecvTimeoutError> {
+        recv_deadline(&self.0, self.1, self.2, deadline)
     }
     pub fn deactivate(self) -> InactiveReceiver<'ch, T> {
         InactiveReceiver(self.1)
@@ -36,24 +92,162 @@ impl<'ch, T> Receiver<'ch, T> {
         self.1.id().map(Into::into).expect("Bad id")
     }
 }
+/// # Panics
+///
+/// Panics when the service has no free subscriber slot. Use
+/// [`Receiver::try_clone`] where the subscriber limit can be reached.
 impl<'ch, T> Clone for Receiver<'ch, T> {
     fn clone(&self) -> Self {
         Self::new(self.1)
     }
 }
+#[cfg(feature = "futures-util")]
+impl<'ch, T> Stream for Receiver<'ch, T> {
+    type Item = T;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let (field, subscriber) = (self.1, self.2);
+        poll_recv(&mut self.0, field, subscriber, cx)
+    }
+}
 impl<'ch, T> Drop for Receiver<'ch, T> {
     fn drop(&mut self) {
-        let mut to_clear = false;
-        self.1.state(&mut |state| to_clear = state.decr());
-        if to_clear {
-            while self.0.try_recv().is_ok() {}
-        }
+        release(self.1, self.2)
     }
 }
 
 pub struct InactiveReceiver<'ch, T>(&'ch dyn DynamicService<T>);
 impl<'ch, T> InactiveReceiver<'ch, T> {
+    /// # Panics
+    ///
+    /// Panics when the service has no free subscriber slot.
     pub fn activate(self) -> Receiver<'ch, T> {
         Receiver::new(self.0)
     }
+    /// Returns `None` while the service has no free subscriber slot, keeping
+    /// `self` so activation can be retried.
+    pub fn try_activate(&self) -> Option<Receiver<'ch, T>> {
+        Receiver::try_new(self.0)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(super) async fn recv<T>(
+    source: &private::Source<'_, T>,
+    field: &dyn DynamicService<T>,
+    subscriber: usize,
+) -> T {
+    match source {
+        private::Source::Channel(receiver) => {
+            let event = prelude::recv(receiver).await.unwrap();
+            field.dequeued(0);
+            field.metrics(&mut |metrics| metrics.received += 1);
+            event
+        }
+        private::Source::Doorbell(_, doorbell) => loop {
+            if let Ok(event) = field.take_for(subscriber) {
+                field.metrics(&mut |metrics| metrics.received += 1);
+                break event;
+            }
+            prelude::recv(doorbell).await;
+        },
+    }
+}
+
+#[cfg(feature = "futures-util")]
+pub(super) fn poll_recv<T>(
+    source: &mut private::Source<'_, T>,
+    field: &dyn DynamicService<T>,
+    subscriber: usize,
+    cx: &mut Context<'_>,
+) -> Poll<Option<T>> {
+    let event = match source {
+        private::Source::Channel(receiver) => {
+            let event = core::task::ready!(Pin::new(receiver).poll_next(cx));
+            if event.is_some() {
+                field.dequeued(0);
+            }
+            event
+        }
+        private::Source::Doorbell(_, doorbell) => loop {
+            if let Ok(event) = field.take_for(subscriber) {
+                break Some(event);
+            }
+            if core::task::ready!(Pin::new(&mut *doorbell).poll_next(cx)).is_none() {
+                break None;
+            }
+        },
+    };
+    if event.is_some() {
+        field.metrics(&mut |metrics| metrics.received += 1);
+    }
+    Poll::Ready(event)
+}
+
+#[cfg(feature = "std")]
+pub(super) fn recv<T>(
+    source: &private::Source<'_, T>,
+    field: &dyn DynamicService<T>,
+    subscriber: usize,
+) -> T {
+    let event = match source {
+        private::Source::Channel(receiver) => {
+            let event = receiver.recv().unwrap();
+            field.dequeued(0);
+            event
+        }
+        private::Source::Doorbell(_, doorbell) => loop {
+            if let Ok(event) = field.take_for(subscriber) {
+                break event;
+            }
+            let _ = doorbell.recv();
+        },
+    };
+    field.metrics(&mut |metrics| metrics.received += 1);
+    event
+}
+
+#[cfg(feature = "std")]
+pub(super) fn recv_deadline<T>(
+    source: &private::Source<'_, T>,
+    field: &dyn DynamicService<T>,
+    subscriber: usize,
+    deadline: std::time::Instant,
+) -> Result<T, RecvTimeoutError> {
+    let remaining = || deadline.saturating_duration_since(std::time::Instant::now());
+    let event = match source {
+        private::Source::Channel(receiver) => {
+            let event = receiver.recv_timeout(remaining())?;
+            field.dequeued(0);
+            event
+        }
+        private::Source::Doorbell(_, doorbell) => loop {
+            if let Ok(event) = field.take_for(subscriber) {
+                break event;
+            }
+            doorbell.recv_timeout(remaining())?;
+        },
+    };
+    field.metrics(&mut |metrics| metrics.received += 1);
+    Ok(event)
+}
+
+pub(super) fn try_recv<T>(
+    field: &dyn DynamicService<T>,
+    subscriber: usize,
+) -> Result<T, prelude::RecvError> {
+    let event = field.take_for(subscriber)?;
+    field.metrics(&mut |metrics| metrics.received += 1);
+    Ok(event)
+}
+
+pub(super) fn release<T>(field: &dyn DynamicService<T>, subscriber: usize) {
+    field.unsubscribe(subscriber);
+    let mut to_clear = false;
+    field.state(&mut |state| to_clear = state.decr());
+    if to_clear {
+        while let Ok(event) = field.take() {
+            field.abandon(event);
+            field.metrics(&mut |metrics| metrics.dropped_no_receiver += 1);
+        }
+    }
 }
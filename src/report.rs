@@ -0,0 +1,208 @@
+use super::*;
+use core::fmt::Debug;
+
+pub const REPORT_CAPACITY: usize = 8;
+
+#[derive(Debug)]
+pub enum Delivery<T> {
+    Delivered,
+    Full(T),
+    Disconnected(T),
+    Vetoed(T),
+    RateLimited(T),
+}
+impl<T> Delivery<T> {
+    pub fn is_delivered(&self) -> bool {
+        matches!(self, Self::Delivered)
+    }
+    pub fn is_full(&self) -> bool {
+        matches!(self, Self::Full(_))
+    }
+    pub fn is_disconnected(&self) -> bool {
+        matches!(self, Self::Disconnected(_))
+    }
+    pub fn is_vetoed(&self) -> bool {
+        matches!(self, Self::Vetoed(_))
+    }
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::RateLimited(_))
+    }
+    pub fn into_inner(self) -> Option<T> {
+        match self {
+            Self::Delivered => None,
+            Self::Full(event)
+            | Self::Disconnected(event)
+            | Self::Vetoed(event)
+            | Self::RateLimited(event) => Some(event),
+        }
+    }
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Delivery<U> {
+        match self {
+            Self::Delivered => Delivery::Delivered,
+            Self::Full(event) => Delivery::Full(f(event)),
+            Self::Disconnected(event) => Delivery::Disconnected(f(event)),
+            Self::Vetoed(event) => Delivery::Vetoed(f(event)),
+            Self::RateLimited(event) => Delivery::RateLimited(f(event)),
+        }
+    }
+}
+impl<T> From<Result<(), prelude::SendError<T>>> for Delivery<T> {
+    fn from(res: Result<(), prelude::SendError<T>>) -> Self {
+        match res {
+            Ok(()) => Self::Delivered,
+            Err(prelude::SendError::Full(event)) => Self::Full(event),
+            #[cfg(any(feature = "async-channel", feature = "async-std"))]
+            Err(prelude::SendError::Closed(event)) => Self::Disconnected(event),
+            #[cfg(feature = "std")]
+            Err(prelude::SendError::Disconnected(event)) => Self::Disconnected(event),
+        }
+    }
+}
+
+pub struct SendReport<T> {
+    entries: prelude::Vec<(ID, Delivery<T>)>,
+    skipped: usize,
+    lost: usize,
+}
+impl<T> SendReport<T> {
+    pub(super) fn new() -> Self {
+        Self {
+            entries: prelude::Vec::new(),
+            skipped: 0,
+            lost: 0,
+        }
+    }
+    pub(super) fn push(&mut self, id: ID, delivery: Delivery<T>) {
+        let (id, delivery) = match prelude::push(&mut self.entries, (id, delivery)) {
+            Ok(()) => return,
+            Err(entry) => entry,
+        };
+        self.skipped += 1;
+        if delivery.is_delivered() {
+            return;
+        }
+        match self
+            .entries
+            .iter_mut()
+            .find(|(_, stored)| stored.is_delivered())
+        {
+            Some(entry) => *entry = (id, delivery),
+            None => {
+                log::error!("Report is full, dropped undelivered event for {id}");
+                self.lost += 1;
+            }
+        }
+    }
+    pub(super) fn into_result(self) -> Result<(), Error<T>> {
+        match self.is_delivered() {
+            true => Ok(()),
+            false => Err(Error::Send(self)),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&ID, &Delivery<T>)> + '_ {
+        self.entries.iter().map(|(id, delivery)| (id, delivery))
+    }
+    pub fn is_delivered(&self) -> bool {
+        self.lost == 0 && self.iter().all(|(_, delivery)| delivery.is_delivered())
+    }
+    pub fn delivered(&self) -> impl Iterator<Item = &ID> + '_ {
+        self.iter()
+            .filter_map(|(id, delivery)| delivery.is_delivered().then_some(id))
+    }
+    pub fn full(&self) -> impl Iterator<Item = &ID> + '_ {
+        self.iter()
+            .filter_map(|(id, delivery)| delivery.is_full().then_some(id))
+    }
+    pub fn disconnected(&self) -> impl Iterator<Item = &ID> + '_ {
+        self.iter()
+            .filter_map(|(id, delivery)| delivery.is_disconnected().then_some(id))
+    }
+    pub fn vetoed(&self) -> impl Iterator<Item = &ID> + '_ {
+        self.iter()
+            .filter_map(|(id, delivery)| delivery.is_vetoed().then_some(id))
+    }
+    pub fn rate_limited(&self) -> impl Iterator<Item = &ID> + '_ {
+        self.iter()
+            .filter_map(|(id, delivery)| delivery.is_rate_limited().then_some(id))
+    }
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+    pub fn lost(&self) -> usize {
+        self.lost
+    }
+    pub fn into_undelivered(self) -> impl Iterator<Item = (ID, T)> {
+        self.entries
+            .into_iter()
+            .filter_map(|(id, delivery)| delivery.into_inner().map(|event| (id, event)))
+    }
+}
+impl<T: Debug> Debug for SendReport<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut map = f.debug_map();
+        for (id, delivery) in self.iter() {
+            map.entry(&format_args!("{id}"), delivery);
+        }
+        if self.skipped > 0 {
+            map.entry(&"skipped", &self.skipped);
+        }
+        if self.lost > 0 {
+            map.entry(&"lost", &self.lost);
+        }
+        map.finish()
+    }
+}
+
+pub struct BatchReport {
+    entries: prelude::Vec<(ID, usize)>,
+    events: usize,
+}
+impl BatchReport {
+    pub(super) fn new() -> Self {
+        Self {
+            entries: prelude::Vec::new(),
+            events: 0,
+        }
+    }
+    pub(super) fn push(&mut self, id: ID) -> bool {
+        prelude::push(&mut self.entries, (id, 0)).is_ok()
+    }
+    pub(super) fn offer(&mut self) {
+        self.events += 1;
+    }
+    pub(super) fn accept(&mut self, index: usize) {
+        if let Some((_, accepted)) = self.entries.get_mut(index) {
+            *accepted += 1;
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&ID, usize)> + '_ {
+        self.entries.iter().map(|(id, accepted)| (id, *accepted))
+    }
+    pub fn accepted(&self, target: impl Into<ID>) -> usize {
+        let target = target.into();
+        self.iter()
+            .filter(|(id, _)| id.eq_target(&target))
+            .map(|(_, accepted)| accepted)
+            .sum()
+    }
+    pub fn events(&self) -> usize {
+        self.events
+    }
+    pub fn is_delivered(&self) -> bool {
+        self.iter().all(|(_, accepted)| accepted == self.events)
+    }
+}
+impl Debug for BatchReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut map = f.debug_map();
+        for (id, accepted) in self.iter() {
+            map.entry(
+                &format_args!("{id}"),
+                &format_args!("{accepted}/{}", self.events),
+            );
+        }
+        map.finish()
+    }
+}
@@ -0,0 +1,206 @@
+use super::*;
+use core::{fmt::Debug, marker::PhantomData};
+
+pub trait Exchange {
+    type Response;
+    fn slot(&self) -> Slot;
+}
+
+#[derive(Clone, Copy)]
+pub struct Slot(usize, usize);
+
+pub struct Request<Req, Resp>(Req, Slot, PhantomData<fn() -> Resp>);
+impl<Req, Resp> Request<Req, Resp> {
+    #[cfg(not(feature = "std"))]
+    pub(super) fn new(payload: Req, slot: Slot) -> Self {
+        Self(payload, slot, PhantomData)
+    }
+    pub fn payload(&self) -> &Req {
+        &self.0
+    }
+    pub fn into_payload(self) -> Req {
+        self.0
+    }
+}
+impl<Req, Resp> Exchange for Request<Req, Resp> {
+    type Response = Resp;
+    fn slot(&self) -> Slot {
+        self.1
+    }
+}
+impl<Req: Debug, Resp> Debug for Request<Req, Resp> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Request").field(&self.0).finish()
+    }
+}
+
+pub struct Responder<'ch, T: Exchange>(&'ch dyn DynamicService<T>, Option<Slot>);
+impl<'ch, T: Exchange> Responder<'ch, T> {
+    pub fn respond(mut self, response: T::Response) -> Result<(), T::Response> {
+        match self.1.take() {
+            Some(slot) => self
+                .0
+                .send_reply(slot, Some(response))
+                .map_err(|response| response.unwrap()),
+            None => Err(response),
+        }
+    }
+}
+impl<'ch, T: Exchange> Drop for Responder<'ch, T> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.1.take() {
+            let _ = self.0.send_reply(slot, None);
+        }
+    }
+}
+
+impl<'ch, Req, Resp> Receiver<'ch, Request<Req, Resp>> {
+    #[cfg(not(feature = "std"))]
+    pub async fn recv_request(&mut self) -> (Req, Responder<'ch, Request<Req, Resp>>) {
+        let Request(payload, slot, _) = self.recv().await;
+        (payload, Responder(self.1, Some(slot)))
+    }
+    pub fn try_recv_request(
+        &mut self,
+    ) -> Result<(Req, Responder<'ch, Request<Req, Resp>>), prelude::RecvError> {
+        let Request(payload, slot, _) = self.try_recv()?;
+        Ok((payload, Responder(self.1, Some(slot))))
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(super) struct Pending<'ch, T>(&'ch dyn DynamicSender<T>, Slot);
+#[cfg(not(feature = "std"))]
+impl<'ch, T> Pending<'ch, T> {
+    pub(super) fn new(field: &'ch dyn DynamicSender<T>) -> Option<Self> {
+        field.open_reply().map(|slot| Self(field, slot))
+    }
+    pub(super) fn slot(&self) -> Slot {
+        self.1
+    }
+}
+#[cfg(not(feature = "std"))]
+impl<'ch, T> Drop for Pending<'ch, T> {
+    fn drop(&mut self) {
+        self.0.close_reply(self.1)
+    }
+}
+
+struct Replies<Resp, const N: usize>(
+    prelude::Mutex<[(bool, usize); N]>,
+    [prelude::Channel<Option<Resp>, 1>; N],
+);
+impl<Resp, const N: usize> Replies<Resp, N> {
+    fn new() -> Self {
+        Self(
+            prelude::Mutex::new([(false, 0); N]),
+            core::array::from_fn(|_| prelude::Channel::new()),
+        )
+    }
+    fn open(&self) -> Option<Slot> {
+        self.0.lock(|slots| {
+            let (index, (busy, generation)) =
+                slots.iter_mut().enumerate().find(|(_, (busy, _))| !*busy)?;
+            *busy = true;
+            *generation = generation.wrapping_add(1);
+            while self.receiver(index).try_recv().is_ok() {}
+            Some(Slot(index, *generation))
+        })
+    }
+    fn close(&self, Slot(index, generation): Slot) {
+        self.0.lock(|slots| {
+            if slots[index].1 == generation {
+                slots[index].0 = false;
+            }
+        })
+    }
+    fn send(
+        &self,
+        Slot(index, generation): Slot,
+        response: Option<Resp>,
+    ) -> Result<(), Option<Resp>> {
+        self.0.lock(|slots| match slots[index] {
+            (true, current) if current == generation => {
+                let sender = prelude::sender(&self.1[index]);
+                let res = sender.try_send(response);
+                Delivery::from(res).into_inner().map_or(Ok(()), Err)
+            }
+            _ => Err(response),
+        })
+    }
+    fn receiver(&self, index: usize) -> prelude::Receiver<'_, Option<Resp>> {
+        prelude::receiver(&self.1[index])
+    }
+}
+
+pub struct RequestService<T: Exchange, const N: usize>(Service<T, N>, Replies<T::Response, N>);
+impl<T: Exchange, const N: usize> RequestService<T, N> {
+    pub fn init(&mut self, id: impl Into<ID>) {
+        self.0.init(id)
+    }
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.0.set_overflow(overflow)
+    }
+}
+impl<T: Exchange, const N: usize> Default for RequestService<T, N> {
+    fn default() -> Self {
+        Self(Service::default(), Replies::new())
+    }
+}
+impl<T: Exchange, const N: usize> private::DynamicServiceId for RequestService<T, N> {
+    fn id(&self) -> &Option<ID> {
+        self.0.id()
+    }
+}
+impl<T: Exchange, const N: usize> private::DynamicServiceState for RequestService<T, N> {
+    fn state(&self, call: &mut dyn FnMut(&mut State)) {
+        self.0.state(call)
+    }
+}
+impl<T: Exchange, const N: usize> private::DynamicServiceMetrics for RequestService<T, N> {
+    fn metrics(&self, call: &mut dyn FnMut(&mut Metrics)) {
+        self.0.metrics(call)
+    }
+}
+impl<T: Exchange, const N: usize> private::DynamicService<T> for RequestService<T, N> {
+    fn source(&self) -> private::Source<'_, T> {
+        self.0.source()
+    }
+}
+impl<T: Exchange, const N: usize> private::DynamicSender<T> for RequestService<T, N> {
+    fn slot_sender(&self, priority: usize) -> prelude::Sender<'_, ()> {
+        self.0.slot_sender(priority)
+    }
+    fn slot_receiver(&self, priority: usize) -> prelude::Receiver<'_, ()> {
+        self.0.slot_receiver(priority)
+    }
+    fn overflow(&self) -> Overflow {
+        self.0.overflow()
+    }
+    fn discard(&self, priority: usize) -> Option<T> {
+        self.0.discard(priority)
+    }
+    fn push(&self, from: &ID, priority: usize, event: T) -> Delivery<T> {
+        self.0.push(from, priority, event)
+    }
+
+    fn open_reply(&self) -> Option<Slot> {
+        self.1.open()
+    }
+    fn close_reply(&self, slot: Slot) {
+        self.1.close(slot)
+    }
+    fn abandon(&self, request: T) {
+        let _ = self.1.send(request.slot(), None);
+    }
+    fn send_reply(
+        &self,
+        slot: Slot,
+        response: Option<T::Response>,
+    ) -> Result<(), Option<T::Response>> {
+        self.1.send(slot, response)
+    }
+    fn reply_receiver(&self, slot: Slot) -> Option<prelude::Receiver<'_, Option<T::Response>>> {
+        Some(self.1.receiver(slot.0))
+    }
+}
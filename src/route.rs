@@ -0,0 +1,20 @@
+pub type Route = (usize, &'static [usize]);
+
+pub const fn allows(routes: &[Route], from: usize, to: usize) -> bool {
+    let mut index = 0;
+    while index < routes.len() {
+        let (source, targets) = routes[index];
+        if source == from {
+            let mut target = 0;
+            while target < targets.len() {
+                if targets[target] == to {
+                    return true;
+                }
+                target += 1;
+            }
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
@@ -0,0 +1,167 @@
+use super::*;
+use core::fmt::Debug;
+
+pub const SCHEDULE_CAPACITY: usize = 8;
+
+struct Entry<Tm: Timer, T> {
+    from: ID,
+    target: ID,
+    deadline: Tm::Instant,
+    sequence: usize,
+    debounced: bool,
+    event: T,
+}
+
+type Entries<Tm, T, const S: usize> = (usize, [Option<Entry<Tm, T>>; S]);
+
+pub struct Scheduler<'t, Tm: Timer, T, const S: usize = SCHEDULE_CAPACITY>(
+    &'t Tm,
+    prelude::Mutex<Entries<Tm, T, S>>,
+    prelude::Channel<(), 1>,
+);
+impl<'t, Tm, T, const S: usize> Scheduler<'t, Tm, T, S>
+where
+    Tm: Timer,
+    Tm::Instant: Ord,
+{
+    pub fn new(timer: &'t Tm) -> Self {
+        Self(
+            timer,
+            prelude::Mutex::new((0, core::array::from_fn(|_| None))),
+            prelude::Channel::new(),
+        )
+    }
+    pub fn timer(&self) -> &'t Tm {
+        self.0
+    }
+    pub fn pending(&self) -> usize {
+        self.1.lock(|(_, entries)| entries.iter().flatten().count())
+    }
+    pub fn cancel(&self, target: impl Into<ID>) -> usize {
+        let target = target.into();
+        self.1.lock(|(_, entries)| {
+            entries
+                .iter_mut()
+                .filter(|entry| {
+                    entry
+                        .as_ref()
+                        .map_or(false, |entry| target.eq_target(&entry.target))
+                })
+                .map(|entry| entry.take())
+                .count()
+        })
+    }
+
+    pub async fn run<Notif>(&self, notif: &Notif)
+    where
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        let doorbell = prelude::receiver(&self.2);
+        loop {
+            while let Some((from, target, event)) = self.take_due() {
+                if let Err(err) = notif.sender(from).send_to([target], event) {
+                    log::error!("Scheduled send from {from} to {target} failed: {err:?}");
+                }
+            }
+            match self.next_deadline() {
+                Some(deadline) => {
+                    with_deadline(self.0, deadline, prelude::recv(&doorbell)).await;
+                }
+                None => {
+                    prelude::recv(&doorbell).await;
+                }
+            }
+        }
+    }
+
+    pub(super) fn schedule(
+        &self,
+        from: ID,
+        target: ID,
+        deadline: Tm::Instant,
+        event: T,
+    ) -> Result<(), T> {
+        self.insert(from, target, deadline, false, event)?;
+        self.ring();
+        Ok(())
+    }
+    pub(super) fn debounce(
+        &self,
+        from: ID,
+        target: ID,
+        deadline: Tm::Instant,
+        event: T,
+    ) -> Result<bool, T> {
+        let event = self.1.lock(|(_, entries)| {
+            match entries
+                .iter_mut()
+                .flatten()
+                .find(|entry| entry.debounced && entry.from == from && entry.target == target)
+            {
+                Some(entry) => {
+                    entry.deadline = deadline;
+                    entry.event = event;
+                    None
+                }
+                None => Some(event),
+            }
+        });
+        let replaced = match event {
+            Some(event) => {
+                self.insert(from, target, deadline, true, event)?;
+                false
+            }
+            None => true,
+        };
+        self.ring();
+        Ok(replaced)
+    }
+
+    fn insert(
+        &self,
+        from: ID,
+        target: ID,
+        deadline: Tm::Instant,
+        debounced: bool,
+        event: T,
+    ) -> Result<(), T> {
+        self.1.lock(|(sequence, entries)| {
+            let slot = match entries.iter_mut().find(|entry| entry.is_none()) {
+                Some(slot) => slot,
+                None => return Err(event),
+            };
+            *sequence = sequence.wrapping_add(1);
+            *slot = Some(Entry {
+                from,
+                target,
+                deadline,
+                sequence: *sequence,
+                debounced,
+                event,
+            });
+            Ok(())
+        })
+    }
+
+    fn ring(&self) {
+        let _ = prelude::sender(&self.2).try_send(());
+    }
+
+    fn take_due(&self) -> Option<(ID, ID, T)> {
+        let now = self.0.now();
+        self.1.lock(|(_, entries)| {
+            let slot = entries
+                .iter_mut()
+                .filter(|entry| entry.as_ref().map_or(false, |entry| entry.deadline <= now))
+                .min_by_key(|entry| entry.as_ref().map(|entry| (entry.deadline, entry.sequence)))?;
+            let entry = slot.take()?;
+            Some((entry.from, entry.target, entry.event))
+        })
+    }
+
+    fn next_deadline(&self) -> Option<Tm::Instant> {
+        self.1
+            .lock(|(_, entries)| entries.iter().flatten().map(|entry| entry.deadline).min())
+    }
+}
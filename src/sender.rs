@@ -1,13 +1,93 @@
 use super::*;
 use core::fmt::Debug;
 
-#[derive(Clone, Copy)]
-pub struct Sender<'notif, Notif>(pub(super) ID, pub(super) &'notif Notif);
+pub(super) type Field<'s, T> = (&'s ID, &'s dyn DynamicSender<T>);
+
+pub struct Sender<'notif, Notif>(
+    pub(super) ID,
+    pub(super) &'notif Notif,
+    pub(super) Option<&'notif dyn RateLimit>,
+    pub(super) bool,
+);
+impl<'notif, Notif> Clone for Sender<'notif, Notif> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'notif, Notif> Copy for Sender<'notif, Notif> {}
 impl<'notif, Notif> Sender<'notif, Notif> {
     pub fn id(&self) -> ID {
         self.0
     }
 
+    pub fn with_limiter(mut self, limiter: &'notif dyn RateLimit) -> Self {
+        self.2 = Some(limiter);
+        self
+    }
+
+    /// Lets `send`, `send_filtered`, `send_with_priority`, `send_batch` and
+    /// their async variants deliver to the sender's own target. Off by
+    /// default. `send_to` and the other methods that name their targets, as
+    /// well as `send_where`, reach every target they select, including the
+    /// sender's own.
+    pub fn with_loopback(mut self, loopback: bool) -> Self {
+        self.3 = loopback;
+        self
+    }
+
+    pub fn loopback(&self) -> bool {
+        self.3
+    }
+
+    #[inline]
+    pub fn for_type<T: 'notif>(&self) -> Result<TypedSender<'notif, T>, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        self.for_type_with_capacity()
+    }
+
+    pub fn for_type_with_capacity<T: 'notif, const N: usize>(
+        &self,
+    ) -> Result<TypedSender<'notif, T, N>, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        TypedSender::new(self, |id| self.reaches(id), true)
+    }
+
+    #[inline]
+    pub fn route_to<Tg, T: 'notif, const S: usize>(
+        &self,
+        targets: [Tg; S],
+    ) -> Result<TypedSender<'notif, T>, Error<T>>
+    where
+        Tg: Copy,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        self.route_to_with_capacity(targets)
+    }
+
+    pub fn route_to_with_capacity<Tg, T: 'notif, const S: usize, const N: usize>(
+        &self,
+        targets: [Tg; S],
+    ) -> Result<TypedSender<'notif, T, N>, Error<T>>
+    where
+        Tg: Copy,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        let targets = targets.map(ID::from);
+        self.check_routes(targets)?;
+
+        TypedSender::new(
+            self,
+            |id| targets.iter().any(|t_id| id.eq_target(t_id)),
+            false,
+        )
+    }
+
     #[inline]
     pub fn send<T: Debug + Clone>(&self, event: T) -> Result<(), Error<T>>
     where
@@ -28,12 +108,17 @@ impl<'notif, Notif> Sender<'notif, Notif> {
         let filter = filter.map(ID::from);
         self.send_impl(
             move |id, state| {
-                id != &self.0 && state.is_active() && filter.iter().all(|t_id| !id.eq_target(t_id))
+                self.reaches(id)
+                    && state.is_active()
+                    && filter.iter().all(|t_id| !id.eq_target(t_id))
             },
+            0,
             event,
         )
     }
 
+    /// Sends to every named target, including the sender's own target
+    /// regardless of [`Sender::with_loopback`].
     pub fn send_to<Tg, T, const S: usize>(&self, targets: [Tg; S], event: T) -> Result<(), Error<T>>
     where
         Tg: Copy,
@@ -42,52 +127,871 @@ impl<'notif, Notif> Sender<'notif, Notif> {
         Notif: NotifierSenders<T>,
     {
         let targets = targets.map(ID::from);
+        self.check_routes(targets)?;
+
+        self.send_impl(
+            |id, _| targets.iter().any(|t_id| id.eq_target(t_id)),
+            0,
+            event,
+        )
+    }
 
-        self.send_impl(|id, _| targets.iter().any(|t_id| id.eq_target(t_id)), event)
+    pub fn send_filtered_iter<I, T>(&self, filter: I, event: T) -> Result<(), Error<T>>
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: Into<ID>,
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        let filter = filter.into_iter();
+        self.send_impl(
+            move |id, state| {
+                self.reaches(id)
+                    && state.is_active()
+                    && filter.clone().all(|t_id| !id.eq_target(&t_id.into()))
+            },
+            0,
+            event,
+        )
     }
 
-    fn send_impl<F, T: Debug + Clone>(&self, mut filter: F, event: T) -> Result<(), Error<T>>
+    pub fn send_to_iter<I, T>(&self, targets: I, event: T) -> Result<(), Error<T>>
     where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: Into<ID>,
+        T: Debug + Clone,
         Notif: NotifierSenders<T>,
+    {
+        let targets = targets.into_iter();
+        self.check_routes(targets.clone().map(Into::into))?;
+
+        self.send_impl(
+            move |id, _| targets.clone().any(|t_id| id.eq_target(&t_id.into())),
+            0,
+            event,
+        )
+    }
+
+    pub fn send_where<F, T>(&self, filter: F, event: T) -> Result<(), Error<T>>
+    where
         F: FnMut(&ID, State) -> bool + Clone,
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        self.send_impl(filter, 0, event)
+    }
+
+    pub fn send_with_priority<T: Debug + Clone>(
+        &self,
+        priority: usize,
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        self.send_impl(
+            |id, state| self.reaches(id) && state.is_active(),
+            priority,
+            event,
+        )
+    }
+
+    pub fn send_batch<I, T>(&self, events: I) -> Result<BatchReport, Error<T>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        self.send_batch_impl(|id, state| self.reaches(id) && state.is_active(), events)
+    }
+
+    pub fn send_batch_to<Tg, I, T, const S: usize>(
+        &self,
+        targets: [Tg; S],
+        events: I,
+    ) -> Result<BatchReport, Error<T>>
+    where
+        Tg: Copy,
+        I: IntoIterator<Item = T>,
+        T: Debug + Clone,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
     {
-        let mut ret = Ok(());
-        let handle_err = |id: &ID, ret: &mut Result<(), Error<T>>, res| {
-            if let Err(err) = res {
-                log::error!("Error sending to {id}");
-                *ret = Err(Error::Send(id.0, err))
-            } else {
-                log::debug!("Sent to {id}");
+        let targets = targets.map(ID::from);
+        self.check_routes(targets)?;
+
+        self.send_batch_impl(
+            |id, _| targets.iter().any(|t_id| id.eq_target(t_id)),
+            events,
+        )
+    }
+
+    pub fn send_filtered_atomic<Target: Copy, T: Debug + Clone, const S: usize>(
+        &self,
+        filter: [Target; S],
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        ID: From<Target>,
+        Notif: NotifierSenders<T>,
+    {
+        let filter = filter.map(ID::from);
+        self.send_impl_atomic(
+            move |id, state| {
+                self.reaches(id)
+                    && state.is_active()
+                    && filter.iter().all(|t_id| !id.eq_target(t_id))
+            },
+            event,
+        )
+    }
+
+    pub fn send_to_atomic<Tg, T, const S: usize>(
+        &self,
+        targets: [Tg; S],
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Tg: Copy,
+        T: Debug + Clone,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        let targets = targets.map(ID::from);
+        self.check_routes(targets)?;
+
+        self.send_impl_atomic(|id, _| targets.iter().any(|t_id| id.eq_target(t_id)), event)
+    }
+
+    pub fn reserve<Tg, T: 'notif>(&self, target: Tg) -> Option<Permit<'notif, T>>
+    where
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        let (from, notif, limiter) = (self.0, self.1, self.2);
+        let target = ID::from(target);
+        notif.get().find_map(move |field| match field.id() {
+            Some(id) if Notif::allows(&from, id) && id.eq_target(&target) && check(limiter, id) => {
+                let permit = Permit::new(field, from, 0, notif)?;
+                admit(limiter, id);
+                Some(permit)
+            }
+            _ => None,
+        })
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub async fn send_async<T: Debug + Clone>(&self, event: T) -> Result<(), Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        self.send_filtered_async([], event).await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_filtered_async<Target: Copy, T: Debug + Clone, const S: usize>(
+        &self,
+        filter: [Target; S],
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        ID: From<Target>,
+        Notif: NotifierSenders<T>,
+    {
+        let filter = filter.map(ID::from);
+        self.send_impl_async(
+            move |id, state| {
+                self.reaches(id)
+                    && state.is_active()
+                    && filter.iter().all(|t_id| !id.eq_target(t_id))
+            },
+            0,
+            event,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_to_async<Tg, T, const S: usize>(
+        &self,
+        targets: [Tg; S],
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Tg: Copy,
+        T: Debug + Clone,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        let targets = targets.map(ID::from);
+        self.check_routes(targets)?;
+
+        self.send_impl_async(
+            |id, _| targets.iter().any(|t_id| id.eq_target(t_id)),
+            0,
+            event,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_filtered_iter_async<I, T>(&self, filter: I, event: T) -> Result<(), Error<T>>
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: Into<ID>,
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        let filter = filter.into_iter();
+        self.send_impl_async(
+            move |id, state| {
+                self.reaches(id)
+                    && state.is_active()
+                    && filter.clone().all(|t_id| !id.eq_target(&t_id.into()))
+            },
+            0,
+            event,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_to_iter_async<I, T>(&self, targets: I, event: T) -> Result<(), Error<T>>
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: Into<ID>,
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        let targets = targets.into_iter();
+        self.check_routes(targets.clone().map(Into::into))?;
+
+        self.send_impl_async(
+            move |id, _| targets.clone().any(|t_id| id.eq_target(&t_id.into())),
+            0,
+            event,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_where_async<F, T>(&self, filter: F, event: T) -> Result<(), Error<T>>
+    where
+        F: FnMut(&ID, State) -> bool + Clone,
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        self.send_impl_async(filter, 0, event).await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_with_priority_async<T: Debug + Clone>(
+        &self,
+        priority: usize,
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        self.send_impl_async(
+            |id, state| self.reaches(id) && state.is_active(),
+            priority,
+            event,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_batch_async<I, T>(&self, events: I) -> Result<BatchReport, Error<T>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Debug + Clone,
+        Notif: NotifierSenders<T>,
+    {
+        self.send_batch_impl_async(|id, state| self.reaches(id) && state.is_active(), events)
+            .await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_batch_to_async<Tg, I, T, const S: usize>(
+        &self,
+        targets: [Tg; S],
+        events: I,
+    ) -> Result<BatchReport, Error<T>>
+    where
+        Tg: Copy,
+        I: IntoIterator<Item = T>,
+        T: Debug + Clone,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        let targets = targets.map(ID::from);
+        self.check_routes(targets)?;
+
+        self.send_batch_impl_async(
+            |id, _| targets.iter().any(|t_id| id.eq_target(t_id)),
+            events,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn send_after<Tm, Tg, T, const S: usize>(
+        &self,
+        scheduler: &Scheduler<'_, Tm, T, S>,
+        after: Tm::Duration,
+        target: Tg,
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Tm: Timer,
+        Tm::Instant: Ord,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        self.send_at(scheduler, scheduler.timer().deadline(after), target, event)
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn send_at<Tm, Tg, T, const S: usize>(
+        &self,
+        scheduler: &Scheduler<'_, Tm, T, S>,
+        deadline: Tm::Instant,
+        target: Tg,
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Tm: Timer,
+        Tm::Instant: Ord,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        let target = ID::from(target);
+        self.check_routes([target])?;
+        if !check(self.2, &target) {
+            let mut report = SendReport::new();
+            report.push(target, Delivery::RateLimited(event));
+            return Err(Error::Send(report));
+        }
+        scheduler
+            .schedule(self.0, target, deadline, event)
+            .map_err(|event| unscheduled(target, event))?;
+        admit(self.2, &target);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn send_debounced<Tm, Tg, T, const L: usize, const S: usize>(
+        &self,
+        limiter: &Limiter<'_, Tm, L>,
+        scheduler: &Scheduler<'_, Tm, T, S>,
+        target: Tg,
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Tm: Timer,
+        Tm::Instant: Ord + core::ops::Add<Tm::Duration, Output = Tm::Instant>,
+        Tm::Duration: Copy,
+        T: Debug + Clone,
+        ID: From<Tg>,
+        Notif: NotifierSenders<T>,
+    {
+        let target = ID::from(target);
+        let window = match limiter.debounce(&target) {
+            Some(window) => window,
+            None => return self.send_to::<ID, T, 1>([target], event),
+        };
+        self.check_routes([target])?;
+        match scheduler.debounce(self.0, target, scheduler.timer().deadline(window), event) {
+            Ok(true) => {
+                log::warn!("Debounced {target}");
+                limiter.debounced(&target);
+                Ok(())
+            }
+            Ok(false) => Ok(()),
+            Err(event) => Err(unscheduled(target, event)),
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn request<Tg, Req, Resp>(&self, target: Tg, request: Req) -> Result<Resp, Error<Req>>
+    where
+        ID: From<Tg>,
+        Notif: NotifierSenders<Request<Req, Resp>>,
+    {
+        let target = ID::from(target);
+        self.check_routes([target])?;
+        let (id, field) = self
+            .targets(move |id, _| id.eq_target(&target))
+            .next()
+            .ok_or(Error::NotInitialized)?;
+        let mut report = SendReport::new();
+
+        let pending = match Pending::new(field) {
+            Some(pending) => pending,
+            None => {
+                log::error!("No reply slot for {id}");
+                report.push(*id, Delivery::Full(request));
+                return Err(Error::Send(report));
             }
         };
+        let delivery =
+            match intercept(self.1, &self.0, id, Request::new(request, pending.slot())) {
+                Ok((request, _)) => send_one(&self.0, field, 0, request).await,
+                Err(vetoed) => vetoed,
+            }
+            .map(Request::into_payload);
+        if !delivery.is_delivered() {
+            handle_res(id, &mut report, delivery);
+            return Err(Error::Send(report));
+        }
+        log::debug!("Sent request to {id}");
+
+        match field.reply_receiver(pending.slot()) {
+            Some(receiver) => prelude::recv(&receiver).await.flatten(),
+            None => None,
+        }
+        .ok_or(Error::NoResponse)
+    }
 
-        let mut slice = self.1.get().filter_map(move |field| match field.id() {
-            Some(id) if filter(id, field.get_state()) => Some((id, field)),
+    fn targets<'s, F, T>(
+        &'s self,
+        mut filter: F,
+    ) -> impl Iterator<Item = (&'s ID, &'s dyn DynamicSender<T>)> + Clone + 's
+    where
+        Notif: NotifierSenders<T>,
+        F: FnMut(&ID, State) -> bool + Clone + 's,
+        T: 's,
+    {
+        self.1.get().filter_map(move |field| match field.id() {
+            Some(id) if Notif::allows(&self.0, id) && filter(id, field.get_state()) => {
+                Some((id, field))
+            }
             _ => None,
-        });
-        let count = slice.clone().count();
-
-        match count {
-            0 => ret = Err(Error::NotInitialized),
-            1 => {
-                let (id, field) = slice.next().unwrap();
-                #[cfg(not(feature = "std"))]
-                let res = field.sender().try_send(event);
-                #[cfg(feature = "std")]
-                let res = field.sender().send(event);
-                handle_err(id, &mut ret, res)
+        })
+    }
+
+    fn forbidden(&self, targets: impl IntoIterator<Item = ID>) -> Option<ID>
+    where
+        Notif: Notifier,
+    {
+        targets.into_iter().find(|id| !Notif::allows(&self.0, id))
+    }
+
+    fn check_routes<T>(&self, targets: impl IntoIterator<Item = ID>) -> Result<(), Error<T>>
+    where
+        Notif: Notifier,
+    {
+        match self.forbidden(targets) {
+            Some(id) => Err(Error::Forbidden(id)),
+            None => Ok(()),
+        }
+    }
+
+    fn reaches(&self, id: &ID) -> bool {
+        self.3 || id != &self.0
+    }
+
+    fn send_impl<F, T: Debug + Clone>(
+        &self,
+        filter: F,
+        priority: usize,
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+        F: FnMut(&ID, State) -> bool + Clone,
+    {
+        let mut report = SendReport::new();
+
+        let slice = self.targets(filter);
+        if slice.clone().next().is_none() {
+            return Err(Error::NotInitialized);
+        }
+        deliver(
+            self.1,
+            self.2,
+            &self.0,
+            slice,
+            priority,
+            event,
+            |_, id, delivery| handle_res(id, &mut report, delivery),
+        );
+
+        report.into_result()
+    }
+
+    #[cfg(not(feature = "std"))]
+    async fn send_impl_async<F, T: Debug + Clone>(
+        &self,
+        filter: F,
+        priority: usize,
+        event: T,
+    ) -> Result<(), Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+        F: FnMut(&ID, State) -> bool + Clone,
+    {
+        let mut report = SendReport::new();
+
+        let slice = self.targets(filter);
+        if slice.clone().next().is_none() {
+            return Err(Error::NotInitialized);
+        }
+        deliver_async(
+            self.1,
+            self.2,
+            &self.0,
+            slice,
+            priority,
+            event,
+            |_, id, delivery| handle_res(id, &mut report, delivery),
+        )
+        .await;
+
+        report.into_result()
+    }
+
+    fn resolve<'s, F, T>(
+        &'s self,
+        filter: F,
+        report: &mut BatchReport,
+    ) -> Result<prelude::Vec<Field<'s, T>>, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+        F: FnMut(&ID, State) -> bool + Clone + 's,
+        T: 's,
+    {
+        let mut resolved = prelude::Vec::new();
+        let mut count = 0;
+        let mut fits = true;
+        for (id, field) in self.targets(filter) {
+            fits &= report.push(*id) && prelude::push(&mut resolved, (id, field)).is_ok();
+            count += 1;
+        }
+        match (count, fits) {
+            (0, _) => Err(Error::NotInitialized),
+            (_, true) => Ok(resolved),
+            (count, false) => {
+                log::error!("{count} targets do not fit into a batch");
+                Err(Error::TooManyTargets(count))
             }
-            _ => {
-                for (id, field) in slice {
-                    #[cfg(not(feature = "std"))]
-                    let res = field.sender().try_send(event.clone());
-                    #[cfg(feature = "std")]
-                    let res = field.sender().send(event.clone());
-                    handle_err(id, &mut ret, res);
+        }
+    }
+
+    fn send_batch_impl<F, I, T>(&self, filter: F, events: I) -> Result<BatchReport, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+        F: FnMut(&ID, State) -> bool + Clone,
+        I: IntoIterator<Item = T>,
+        T: Debug + Clone,
+    {
+        let mut report = BatchReport::new();
+
+        let resolved = self.resolve(filter, &mut report)?;
+
+        for event in events {
+            report.offer();
+            let slice = resolved.iter().copied();
+            deliver(
+                self.1,
+                self.2,
+                &self.0,
+                slice,
+                0,
+                event,
+                |index, id, delivery| match delivery {
+                    Delivery::Delivered => report.accept(index),
+                    _ => log::error!("Error sending to {id}"),
+                },
+            );
+        }
+
+        Ok(report)
+    }
+
+    #[cfg(not(feature = "std"))]
+    async fn send_batch_impl_async<F, I, T>(
+        &self,
+        filter: F,
+        events: I,
+    ) -> Result<BatchReport, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+        F: FnMut(&ID, State) -> bool + Clone,
+        I: IntoIterator<Item = T>,
+        T: Debug + Clone,
+    {
+        let mut report = BatchReport::new();
+
+        let resolved = self.resolve(filter, &mut report)?;
+
+        for event in events {
+            report.offer();
+            let slice = resolved.iter().copied();
+            deliver_async(
+                self.1,
+                self.2,
+                &self.0,
+                slice,
+                0,
+                event,
+                |index, id, delivery| match delivery {
+                    Delivery::Delivered => report.accept(index),
+                    _ => log::error!("Error sending to {id}"),
+                },
+            )
+            .await;
+        }
+
+        Ok(report)
+    }
+
+    fn send_impl_atomic<F, T: Debug + Clone>(&self, filter: F, event: T) -> Result<(), Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+        F: FnMut(&ID, State) -> bool + Clone,
+    {
+        let mut report = SendReport::new();
+
+        let slice = self.targets(filter);
+        if slice.clone().next().is_none() {
+            return Err(Error::NotInitialized);
+        }
+        match slice.clone().find(|(id, _)| !check(self.2, id)) {
+            Some((id, _)) => report.push(*id, Delivery::RateLimited(event)),
+            None => {
+                if self.reserve_all(slice.clone(), &event, &mut report) {
+                    slice.for_each(|(id, _)| {
+                        admit(self.2, id);
+                    });
                 }
             }
+        }
+
+        report.into_result()
+    }
+
+    fn reserve_all<'s, T: Clone + 's>(
+        &self,
+        mut slice: impl Iterator<Item = Field<'s, T>>,
+        event: &T,
+        report: &mut SendReport<T>,
+    ) -> bool
+    where
+        Notif: NotifierInterceptors<T>,
+    {
+        let (id, field) = match slice.next() {
+            Some(next) => next,
+            None => return true,
+        };
+        // Targets that overflow by evicting get no slot here: eviction waits
+        // for the commit, so a rollback never loses queued events.
+        let evicts = field.overflow() != Overflow::Reject;
+        let permit = Permit::try_new(field, self.0, 0, self.1);
+        if permit.is_none() && !evicts {
+            log::error!("Error reserving {id}");
+            report.push(*id, Delivery::Full(event.clone()));
+            return false;
+        }
+        let (accepted, copies) = match intercept(self.1, &self.0, id, event.clone()) {
+            Ok(intercepted) => intercepted,
+            Err(vetoed) => {
+                handle_res(id, report, vetoed);
+                return false;
+            }
+        };
+        let reserved = (0..copies).take_while(|_| field.try_reserve(0)).count();
+        if reserved < copies && !evicts {
+            (0..reserved).for_each(|_| field.release(0));
+            log::error!("Error reserving copies for {id}");
+            report.push(*id, Delivery::Full(accepted));
+            return false;
+        }
+        if !self.reserve_all(slice, event, report) {
+            (0..reserved).for_each(|_| field.release(0));
+            return false;
+        }
+
+        for copy in 0..copies {
+            let delivery = match copy < reserved {
+                true => field.commit(&self.0, 0, accepted.clone()),
+                false => field.try_send(&self.0, 0, accepted.clone()),
+            };
+            if !delivery.is_delivered() {
+                log::error!("Error duplicating to {id}");
+            }
+        }
+        let delivery = match permit {
+            Some(permit) => permit.commit(accepted),
+            None => field.try_send(&self.0, 0, accepted),
+        };
+        handle_res(id, report, delivery);
+        true
+    }
+}
+
+#[cfg(not(feature = "std"))]
+fn unscheduled<T>(target: ID, event: T) -> Error<T> {
+    log::error!("Scheduler is full, cannot schedule event for {target}");
+    let mut report = SendReport::new();
+    report.push(target, Delivery::Full(event));
+    Error::Send(report)
+}
+
+pub(super) fn admit(limiter: Option<&dyn RateLimit>, id: &ID) -> bool {
+    let admitted = limiter.map_or(true, |limiter| limiter.admit(id));
+    if !admitted {
+        log::warn!("Rate limited {id}");
+    }
+    admitted
+}
+
+pub(super) fn check(limiter: Option<&dyn RateLimit>, id: &ID) -> bool {
+    let admitted = limiter.map_or(true, |limiter| limiter.check(id));
+    if !admitted {
+        log::warn!("Rate limited {id}");
+    }
+    admitted
+}
+
+pub(super) struct FanOut<I: Iterator, T>(core::iter::Peekable<I>, Option<T>);
+impl<I: Iterator, T: Clone> Iterator for FanOut<I, T> {
+    type Item = (I::Item, T);
+    fn next(&mut self) -> Option<Self::Item> {
+        let target = self.0.next()?;
+        let event = match self.0.peek() {
+            Some(_) => self.1.clone(),
+            None => self.1.take(),
         };
+        event.map(|event| (target, event))
+    }
+}
 
-        ret
+pub(super) fn fan_out<I: IntoIterator, T>(targets: I, event: T) -> FanOut<I::IntoIter, T> {
+    FanOut(targets.into_iter().peekable(), Some(event))
+}
+
+pub(super) fn deliver<'s, T: Clone + 's>(
+    interceptors: &dyn NotifierInterceptors<T>,
+    limiter: Option<&dyn RateLimit>,
+    from: &ID,
+    targets: impl Iterator<Item = Field<'s, T>>,
+    priority: usize,
+    event: T,
+    mut handle: impl FnMut(usize, &ID, Delivery<T>),
+) {
+    for ((index, (id, field)), event) in fan_out(targets.enumerate(), event) {
+        let delivery = match admit(limiter, id) {
+            true => dispatch(interceptors, from, id, field, priority, event),
+            false => Delivery::RateLimited(event),
+        };
+        handle(index, id, delivery);
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(super) async fn deliver_async<'s, T: Clone + 's>(
+    interceptors: &dyn NotifierInterceptors<T>,
+    limiter: Option<&dyn RateLimit>,
+    from: &ID,
+    targets: impl Iterator<Item = Field<'s, T>>,
+    priority: usize,
+    event: T,
+    mut handle: impl FnMut(usize, &ID, Delivery<T>),
+) {
+    for ((index, (id, field)), event) in fan_out(targets.enumerate(), event) {
+        let delivery = match admit(limiter, id) {
+            true => dispatch_async(interceptors, from, id, field, priority, event).await,
+            false => Delivery::RateLimited(event),
+        };
+        handle(index, id, delivery);
+    }
+}
+
+pub(super) fn handle_res<T>(id: &ID, report: &mut SendReport<T>, delivery: Delivery<T>) {
+    if delivery.is_delivered() {
+        log::debug!("Sent to {id}");
+    } else {
+        log::error!("Error sending to {id}");
+    }
+    report.push(*id, delivery)
+}
+
+#[cfg(not(feature = "std"))]
+pub(super) async fn send_one<T>(
+    from: &ID,
+    field: &dyn DynamicSender<T>,
+    priority: usize,
+    event: T,
+) -> Delivery<T> {
+    match field.overflow() {
+        Overflow::Reject => {
+            let _ = prelude::send(&field.slot_sender(priority), ()).await;
+            field.commit(from, priority, event)
+        }
+        _ => field.try_send(from, priority, event),
+    }
+}
+
+pub(super) fn duplicate<T: Clone>(
+    from: &ID,
+    to: &ID,
+    field: &dyn DynamicSender<T>,
+    priority: usize,
+    event: &T,
+    copies: usize,
+) {
+    for _ in 0..copies {
+        if !field.try_send(from, priority, event.clone()).is_delivered() {
+            log::error!("Error duplicating to {to}");
+        }
+    }
+}
+
+pub(super) fn dispatch<T: Clone>(
+    interceptors: &dyn NotifierInterceptors<T>,
+    from: &ID,
+    to: &ID,
+    field: &dyn DynamicSender<T>,
+    priority: usize,
+    event: T,
+) -> Delivery<T> {
+    match intercept(interceptors, from, to, event) {
+        Ok((event, copies)) => {
+            duplicate(from, to, field, priority, &event, copies);
+            field.try_send(from, priority, event)
+        }
+        Err(vetoed) => vetoed,
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(super) async fn dispatch_async<T: Clone>(
+    interceptors: &dyn NotifierInterceptors<T>,
+    from: &ID,
+    to: &ID,
+    field: &dyn DynamicSender<T>,
+    priority: usize,
+    event: T,
+) -> Delivery<T> {
+    match intercept(interceptors, from, to, event) {
+        Ok((event, copies)) => {
+            for _ in 0..copies {
+                if !send_one(from, field, priority, event.clone())
+                    .await
+                    .is_delivered()
+                {
+                    log::error!("Error duplicating to {to}");
+                }
+            }
+            send_one(from, field, priority, event).await
+        }
+        Err(vetoed) => vetoed,
     }
 }
@@ -1,5 +1,4 @@
 use super::*;
-use core::ops::DerefMut;
 
 #[derive(Clone, Copy)]
 pub enum State {
@@ -38,17 +37,61 @@ impl State {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    #[default]
+    Reject,
+    DropOldest,
+    KeepLatest,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    pub sent: usize,
+    pub dropped_full: usize,
+    /// Events sent to the service with no receiver to take them, or cleared
+    /// when its last receiver dropped. Broadcasting sends skip inactive
+    /// services without counting them here.
+    pub dropped_no_receiver: usize,
+    pub received: usize,
+    pub depth: usize,
+    pub high_water: usize,
+}
+impl Metrics {
+    pub(super) fn enqueued(&mut self) {
+        self.depth += 1;
+        self.high_water = self.high_water.max(self.depth);
+    }
+    pub(super) fn dequeued(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+}
+
 #[derive(Debug)]
 pub enum Error<T> {
     NotInitialized,
-    Send(usize, prelude::SendError<T>),
+    Send(SendReport<T>),
+    NoResponse,
+    Forbidden(ID),
+    TooManyTargets(usize),
 }
 
-pub struct Service<T, const N: usize>(Option<ID>, prelude::Channel<T, N>, prelude::Mutex);
+pub struct Service<T, const N: usize>(
+    Option<ID>,
+    prelude::Channel<T, N>,
+    prelude::Mutex<State>,
+    prelude::Channel<(), N>,
+    Overflow,
+    prelude::Mutex<usize>,
+    prelude::Mutex<Metrics>,
+);
 impl<T, const N: usize> Service<T, N> {
     pub fn init(&mut self, id: impl Into<ID>) {
         self.0 = Some(id.into());
     }
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.4 = overflow;
+    }
 }
 impl Service<(), 0> {
     pub fn array<FS, F, const SIZE: usize, I: Copy>(id: I, arr: &mut [FS; SIZE], mut cb: F)
@@ -63,7 +106,15 @@ impl Service<(), 0> {
 }
 impl<T, const N: usize> Default for Service<T, N> {
     fn default() -> Self {
-        Self(None, prelude::Channel::new(), prelude::Mutex::new())
+        Self(
+            None,
+            prelude::Channel::new(),
+            prelude::Mutex::new(State::Inactive),
+            prelude::Channel::new(),
+            Overflow::default(),
+            prelude::Mutex::new(0),
+            prelude::Mutex::new(Metrics::default()),
+        )
     }
 }
 impl<T, const N: usize> private::DynamicServiceId for Service<T, N> {
@@ -71,26 +122,87 @@ impl<T, const N: usize> private::DynamicServiceId for Service<T, N> {
         &self.0
     }
 }
+impl<T, const N: usize> Service<T, N> {
+    fn enqueue(&self, event: T) -> Delivery<T> {
+        let sender = prelude::sender(&self.1);
+        let res = sender.try_send(event);
+        let delivery = Delivery::from(res);
+        if delivery.is_delivered() {
+            self.6.lock(Metrics::enqueued);
+        }
+        delivery
+    }
+    fn dequeue(&self) -> Option<T> {
+        let receiver = prelude::receiver(&self.1);
+        receiver.try_recv().ok()
+    }
+    fn next_seq(&self) -> usize {
+        self.5.lock(|seq| {
+            let current = *seq;
+            *seq = seq.wrapping_add(1);
+            current
+        })
+    }
+}
 impl<T, const N: usize> private::DynamicService<T> for Service<T, N> {
-    fn sender(&self) -> prelude::Sender<'_, T> {
-        self.1.sender().into()
+    fn source(&self) -> private::Source<'_, T> {
+        private::Source::Channel(prelude::receiver(&self.1))
+    }
+}
+impl<T, const N: usize> private::DynamicSender<T> for Service<T, N> {
+    fn slot_sender(&self, _priority: usize) -> prelude::Sender<'_, ()> {
+        prelude::sender(&self.3)
+    }
+
+    fn slot_receiver(&self, _priority: usize) -> prelude::Receiver<'_, ()> {
+        prelude::receiver(&self.3)
     }
 
-    fn receiver(&self) -> prelude::Receiver<'_, T> {
-        self.1.receiver().into()
+    fn overflow(&self) -> Overflow {
+        self.4
+    }
+
+    fn discard(&self, _priority: usize) -> Option<T> {
+        self.dequeue()
+    }
+
+    fn push(&self, _from: &ID, _priority: usize, event: T) -> Delivery<T> {
+        self.enqueue(event)
     }
 }
-impl<T, const N: usize> private::DynamicServiceState for Service<T, N> {
-    #[cfg(feature = "embassy")]
-    fn state(&self, call: &mut dyn FnMut(&mut State)) {
-        self.2.lock(|cell| call(cell.borrow_mut().deref_mut()));
+impl<T, const N: usize> private::DynamicSender<T> for Service<Envelope<T>, N> {
+    fn slot_sender(&self, _priority: usize) -> prelude::Sender<'_, ()> {
+        prelude::sender(&self.3)
     }
-    #[cfg(any(feature = "async-channel", feature = "async-std"))]
-    fn state(&self, call: &mut dyn FnMut(&mut State)) {
-        call(self.2.lock().deref_mut())
+
+    fn slot_receiver(&self, _priority: usize) -> prelude::Receiver<'_, ()> {
+        prelude::receiver(&self.3)
+    }
+
+    fn overflow(&self) -> Overflow {
+        self.4
     }
-    #[cfg(feature = "std")]
+
+    fn discard(&self, _priority: usize) -> Option<T> {
+        self.dequeue().map(|envelope| envelope.payload)
+    }
+
+    fn push(&self, from: &ID, _priority: usize, event: T) -> Delivery<T> {
+        let envelope = Envelope {
+            from: *from,
+            seq: self.next_seq(),
+            payload: event,
+        };
+        self.enqueue(envelope).map(|envelope| envelope.payload)
+    }
+}
+impl<T, const N: usize> private::DynamicServiceState for Service<T, N> {
     fn state(&self, call: &mut dyn FnMut(&mut State)) {
-        call(self.2.lock().unwrap().deref_mut())
+        self.2.lock(call)
+    }
+}
+impl<T, const N: usize> private::DynamicServiceMetrics for Service<T, N> {
+    fn metrics(&self, call: &mut dyn FnMut(&mut Metrics)) {
+        self.6.lock(call)
     }
 }
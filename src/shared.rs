@@ -0,0 +1,36 @@
+use super::*;
+use core::{fmt::Debug, ops::Deref};
+
+pub struct Shared<T>(prelude::Arc<T>);
+impl<T> Shared<T> {
+    pub fn new(payload: T) -> Self {
+        Self(prelude::Arc::new(payload))
+    }
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        prelude::Arc::ptr_eq(&this.0, &other.0)
+    }
+    pub fn try_unwrap(this: Self) -> Result<T, Self> {
+        prelude::Arc::try_unwrap(this.0).map_err(Self)
+    }
+}
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl<T> Deref for Shared<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> From<T> for Shared<T> {
+    fn from(payload: T) -> Self {
+        Self::new(payload)
+    }
+}
+impl<T: Debug> Debug for Shared<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&*self.0, f)
+    }
+}
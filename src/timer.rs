@@ -0,0 +1,135 @@
+use super::*;
+#[cfg(feature = "async-std-time")]
+extern crate std;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+pub const MANUAL_CLOCK_WAKERS: usize = 8;
+
+pub trait Timer {
+    type Instant: Copy;
+    type Duration;
+    type Sleep<'t>: Future
+    where
+        Self: 't;
+    fn now(&self) -> Self::Instant;
+    fn deadline(&self, after: Self::Duration) -> Self::Instant;
+    fn sleep_until(&self, deadline: Self::Instant) -> Self::Sleep<'_>;
+}
+
+#[cfg(feature = "embassy-time")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EmbassyTimer;
+#[cfg(feature = "embassy-time")]
+impl Timer for EmbassyTimer {
+    type Instant = embassy_time::Instant;
+    type Duration = embassy_time::Duration;
+    type Sleep<'t> = embassy_time::Timer;
+    fn now(&self) -> Self::Instant {
+        embassy_time::Instant::now()
+    }
+    fn deadline(&self, after: Self::Duration) -> Self::Instant {
+        self.now() + after
+    }
+    fn sleep_until(&self, deadline: Self::Instant) -> Self::Sleep<'_> {
+        embassy_time::Timer::at(deadline)
+    }
+}
+
+#[cfg(feature = "async-std-time")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncStdTimer;
+#[cfg(feature = "async-std-time")]
+impl Timer for AsyncStdTimer {
+    type Instant = std::time::Instant;
+    type Duration = std::time::Duration;
+    type Sleep<'t> = async_io::Timer;
+    fn now(&self) -> Self::Instant {
+        std::time::Instant::now()
+    }
+    fn deadline(&self, after: Self::Duration) -> Self::Instant {
+        self.now() + after
+    }
+    fn sleep_until(&self, deadline: Self::Instant) -> Self::Sleep<'_> {
+        async_io::Timer::at(deadline)
+    }
+}
+
+pub struct ManualClock(prelude::Mutex<(u64, [Option<Waker>; MANUAL_CLOCK_WAKERS])>);
+impl ManualClock {
+    pub fn new() -> Self {
+        Self(prelude::Mutex::new((0, Default::default())))
+    }
+    pub fn advance(&self, ticks: u64) {
+        let wakers = self.0.lock(|(now, wakers)| {
+            *now = now.saturating_add(ticks);
+            core::mem::take(wakers)
+        });
+        wakers.into_iter().flatten().for_each(Waker::wake);
+    }
+}
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Timer for ManualClock {
+    type Instant = u64;
+    type Duration = u64;
+    type Sleep<'t> = ManualSleep<'t>;
+    fn now(&self) -> Self::Instant {
+        self.0.lock(|(now, _)| *now)
+    }
+    fn deadline(&self, after: Self::Duration) -> Self::Instant {
+        self.now().saturating_add(after)
+    }
+    fn sleep_until(&self, deadline: Self::Instant) -> Self::Sleep<'_> {
+        ManualSleep(self, deadline)
+    }
+}
+
+pub struct ManualSleep<'t>(&'t ManualClock, u64);
+impl<'t> Future for ManualSleep<'t> {
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let deadline = self.1;
+        self.0 .0.lock(|(now, wakers)| {
+            if *now >= deadline {
+                return Poll::Ready(());
+            }
+            let waker = cx.waker();
+            if wakers
+                .iter()
+                .flatten()
+                .any(|stored| stored.will_wake(waker))
+            {
+                return Poll::Pending;
+            }
+            match wakers.iter_mut().find(|slot| slot.is_none()) {
+                Some(slot) => *slot = Some(waker.clone()),
+                None => waker.wake_by_ref(),
+            }
+            Poll::Pending
+        })
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(super) async fn with_deadline<Tm: Timer, F: Future>(
+    timer: &Tm,
+    deadline: Tm::Instant,
+    future: F,
+) -> Option<F::Output> {
+    let mut future = core::pin::pin!(future);
+    let mut sleep = core::pin::pin!(timer.sleep_until(deadline));
+    core::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        sleep.as_mut().poll(cx).map(|_| None)
+    })
+    .await
+}
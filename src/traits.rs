@@ -4,24 +4,35 @@ pub trait ServiceGet<T> {
     fn get(&self, target: impl Into<ID>) -> Option<&dyn DynamicService<T>>;
 }
 
-pub trait NotifierSenders<T> {
-    type Iter<'ch>: Iterator<Item = &'ch dyn DynamicService<T>> + Clone
+pub trait NotifierSenders<T>: Notifier + NotifierInterceptors<T> {
+    type Iter<'ch>: Iterator<Item = &'ch dyn DynamicSender<T>> + Clone
     where
         T: 'ch,
         Self: 'ch;
     fn get(&self) -> Self::Iter<'_>;
 }
 pub trait DynamicService<T>: private::DynamicService<T> {}
+pub trait DynamicSender<T>: private::DynamicSender<T> {}
 pub trait DynamicServiceId: private::DynamicServiceId {}
 pub trait DynamicServiceState: private::DynamicServiceState {
     fn get_state(&self) -> State {
         private::DynamicServiceState::get_state(self)
     }
 }
+pub trait DynamicServiceMetrics: private::DynamicServiceMetrics {
+    fn get_metrics(&self) -> Metrics {
+        private::DynamicServiceMetrics::get_metrics(self)
+    }
+}
 
 pub trait Notifier: Sized {
+    const ROUTES: &'static [Route] = &[];
+
+    fn allows(from: &ID, to: &ID) -> bool {
+        allows(Self::ROUTES, from.id(), to.id())
+    }
     fn sender(&self, target: impl Into<ID>) -> Sender<Self> {
-        Sender(target.into(), self)
+        Sender(target.into(), self, None, false)
     }
     fn receiver<const ID: usize, T>(&self, index: Option<usize>) -> Receiver<'_, T>
     where
@@ -38,7 +49,7 @@ pub trait Notifier: Sized {
     where
         Self: ServiceGet<T>,
     {
-        self.get(target).map(Receiver::new)
+        self.get(target).and_then(Receiver::try_new)
     }
 }
 
@@ -49,12 +60,135 @@ pub mod marker {
 pub(super) mod private {
     use super::*;
 
-    pub trait DynamicService<T>: DynamicServiceId + DynamicServiceState {
-        fn sender(&self) -> prelude::Sender<'_, T>;
-        fn receiver(&self) -> prelude::Receiver<'_, T>;
+    pub enum Source<'ch, T> {
+        Channel(prelude::Receiver<'ch, T>),
+        Doorbell(&'ch prelude::Channel<(), 1>, prelude::Receiver<'ch, ()>),
+    }
+    impl<'ch, T> Source<'ch, T> {
+        pub fn doorbell(doorbell: &'ch prelude::Channel<(), 1>) -> Self {
+            Self::Doorbell(doorbell, prelude::receiver(doorbell))
+        }
+    }
+
+    pub trait DynamicService<T>: DynamicSender<T> {
+        fn source(&self) -> Source<'_, T>;
+
+        fn take(&self) -> Result<T, prelude::RecvError> {
+            let receiver = match self.source() {
+                Source::Channel(receiver) => receiver,
+                Source::Doorbell(..) => return Err(prelude::RECV_EMPTY),
+            };
+            let event = receiver.try_recv()?;
+            self.dequeued(0);
+            Ok(event)
+        }
+
+        fn subscribe(&self) -> Option<usize> {
+            Some(0)
+        }
+        fn unsubscribe(&self, _subscriber: usize) {}
+        fn subscription(&self, _subscriber: usize) -> Source<'_, T> {
+            self.source()
+        }
+        fn take_for(&self, _subscriber: usize) -> Result<T, prelude::RecvError> {
+            self.take()
+        }
     }
     impl<T, F: DynamicService<T>> super::DynamicService<T> for F {}
 
+    pub trait DynamicSender<T>:
+        DynamicServiceId + DynamicServiceState + DynamicServiceMetrics
+    {
+        fn slot_sender(&self, priority: usize) -> prelude::Sender<'_, ()>;
+        fn slot_receiver(&self, priority: usize) -> prelude::Receiver<'_, ()>;
+        fn overflow(&self) -> Overflow;
+        fn discard(&self, priority: usize) -> Option<T>;
+        fn push(&self, from: &ID, priority: usize, event: T) -> Delivery<T>;
+
+        fn open_reply(&self) -> Option<Slot> {
+            None
+        }
+        fn close_reply(&self, _slot: Slot) {}
+        fn abandon(&self, _event: T) {}
+        fn send_reply(
+            &self,
+            _slot: Slot,
+            response: Option<T::Response>,
+        ) -> Result<(), Option<T::Response>>
+        where
+            T: Exchange,
+        {
+            Err(response)
+        }
+        fn reply_receiver(&self, _slot: Slot) -> Option<prelude::Receiver<'_, Option<T::Response>>>
+        where
+            T: Exchange,
+        {
+            None
+        }
+
+        fn try_reserve(&self, priority: usize) -> bool {
+            self.slot_sender(priority).try_send(()).is_ok()
+        }
+        fn release(&self, priority: usize) {
+            let _ = self.slot_receiver(priority).try_recv();
+        }
+        fn dequeued(&self, priority: usize) {
+            self.release(priority);
+            self.metrics(&mut |metrics| metrics.dequeued());
+        }
+        fn evict(&self, priority: usize) -> bool {
+            let evicted = match self.discard(priority) {
+                Some(event) => event,
+                None => return false,
+            };
+            self.abandon(evicted);
+            self.dequeued(priority);
+            self.metrics(&mut |metrics| metrics.dropped_full += 1);
+            true
+        }
+        fn reserve(&self, priority: usize) -> bool {
+            loop {
+                if self.try_reserve(priority) {
+                    break true;
+                }
+                if self.overflow() == Overflow::Reject || !self.evict(priority) {
+                    break false;
+                }
+            }
+        }
+        fn commit(&self, from: &ID, priority: usize, event: T) -> Delivery<T> {
+            if self.overflow() == Overflow::KeepLatest {
+                while self.evict(priority) {}
+            }
+            let delivery = self.push(from, priority, event);
+            if !delivery.is_delivered() {
+                self.release(priority);
+            }
+            self.record(&delivery);
+            delivery
+        }
+        fn try_send(&self, from: &ID, priority: usize, event: T) -> Delivery<T> {
+            match self.reserve(priority) {
+                true => self.commit(from, priority, event),
+                false => {
+                    let delivery = Delivery::Full(event);
+                    self.record(&delivery);
+                    delivery
+                }
+            }
+        }
+        fn record(&self, delivery: &Delivery<T>) {
+            self.metrics(&mut |metrics| match delivery {
+                Delivery::Delivered => metrics.sent += 1,
+                Delivery::Full(_) => metrics.dropped_full += 1,
+                Delivery::Disconnected(_) => metrics.dropped_no_receiver += 1,
+                Delivery::Vetoed(_) | Delivery::RateLimited(_) => (),
+            })
+        }
+    }
+    impl<T, F: DynamicSender<T>> super::DynamicSender<T> for F {}
+
     pub trait DynamicServiceId {
         fn id(&self) -> &Option<ID>;
     }
@@ -69,4 +203,14 @@ pub(super) mod private {
         fn state(&self, call: &mut dyn FnMut(&mut State));
     }
     impl<F: DynamicServiceState> super::DynamicServiceState for F {}
+
+    pub trait DynamicServiceMetrics {
+        fn get_metrics(&self) -> Metrics {
+            let mut ret = Metrics::default();
+            self.metrics(&mut |metrics| ret = *metrics);
+            ret
+        }
+        fn metrics(&self, call: &mut dyn FnMut(&mut Metrics));
+    }
+    impl<F: DynamicServiceMetrics> super::DynamicServiceMetrics for F {}
 }
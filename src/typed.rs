@@ -0,0 +1,134 @@
+use super::*;
+use core::fmt::Debug;
+
+type Resolved<'notif, T, const N: usize> = [Option<Field<'notif, T>>; N];
+
+pub struct TypedSender<'notif, T, const N: usize = REPORT_CAPACITY>(
+    ID,
+    Resolved<'notif, T, N>,
+    Option<&'notif dyn RateLimit>,
+    bool,
+    &'notif dyn NotifierInterceptors<T>,
+);
+impl<'notif, T, const N: usize> Clone for TypedSender<'notif, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'notif, T, const N: usize> Copy for TypedSender<'notif, T, N> {}
+impl<'notif, T: 'notif, const N: usize> TypedSender<'notif, T, N> {
+    pub(super) fn new<Notif>(
+        sender: &Sender<'notif, Notif>,
+        mut filter: impl FnMut(&ID) -> bool,
+        active_only: bool,
+    ) -> Result<Self, Error<T>>
+    where
+        Notif: NotifierSenders<T>,
+    {
+        let mut resolved = [None; N];
+        let fields = sender.1.get().filter_map(|field| match field.id() {
+            Some(id) if Notif::allows(&sender.0, id) && filter(id) => Some((id, field)),
+            _ => None,
+        });
+        let mut count = 0;
+        for target in fields {
+            if let Some(slot) = resolved.get_mut(count) {
+                *slot = Some(target);
+            }
+            count += 1;
+        }
+        if count > N {
+            log::error!("{count} targets do not fit into a handle of {N}");
+            return Err(Error::TooManyTargets(count));
+        }
+        Ok(Self(sender.0, resolved, sender.2, active_only, sender.1))
+    }
+
+    pub fn id(&self) -> ID {
+        self.0
+    }
+
+    pub fn targets(&self) -> impl Iterator<Item = &'notif ID> + '_ {
+        self.1.iter().flatten().map(|(id, _)| *id)
+    }
+
+    #[inline]
+    pub fn send(&self, event: T) -> Result<(), Error<T>>
+    where
+        T: Debug + Clone,
+    {
+        self.send_with_priority(0, event)
+    }
+
+    pub fn send_with_priority(&self, priority: usize, event: T) -> Result<(), Error<T>>
+    where
+        T: Debug + Clone,
+    {
+        let mut report = SendReport::new();
+
+        let (slice, count) = self.select();
+        if count == 0 {
+            return Err(Error::NotInitialized);
+        }
+        let slice = slice.into_iter().flatten();
+        deliver(
+            self.4,
+            self.2,
+            &self.0,
+            slice,
+            priority,
+            event,
+            |_, id, delivery| handle_res(id, &mut report, delivery),
+        );
+
+        report.into_result()
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub async fn send_async(&self, event: T) -> Result<(), Error<T>>
+    where
+        T: Debug + Clone,
+    {
+        self.send_with_priority_async(0, event).await
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub async fn send_with_priority_async(&self, priority: usize, event: T) -> Result<(), Error<T>>
+    where
+        T: Debug + Clone,
+    {
+        let mut report = SendReport::new();
+
+        let (slice, count) = self.select();
+        if count == 0 {
+            return Err(Error::NotInitialized);
+        }
+        let slice = slice.into_iter().flatten();
+        deliver_async(
+            self.4,
+            self.2,
+            &self.0,
+            slice,
+            priority,
+            event,
+            |_, id, delivery| handle_res(id, &mut report, delivery),
+        )
+        .await;
+
+        report.into_result()
+    }
+
+    fn select(&self) -> (Resolved<'notif, T, N>, usize) {
+        let mut selected = [None; N];
+        let mut count = 0;
+        for (id, field) in self.1.iter().flatten() {
+            if self.3 && !field.get_state().is_active() {
+                continue;
+            }
+            selected[count] = Some((*id, *field));
+            count += 1;
+        }
+        (selected, count)
+    }
+}